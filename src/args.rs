@@ -0,0 +1,116 @@
+use std::env;
+use std::path::PathBuf;
+
+pub enum Command {
+    Solve(String),
+    Leaderboard(Option<u64>),
+    Fetch(Vec<u32>),
+    Generate(u32),
+}
+
+pub struct Args {
+    pub command: Command,
+    pub session_file: Option<PathBuf>,
+    pub offline: bool,
+    pub year: Option<u32>,
+    pub normalize: bool,
+    pub raw: bool,
+    pub input: Option<PathBuf>,
+    pub size: Option<String>,
+    pub seed: Option<u64>,
+    pub output: Option<PathBuf>,
+    pub reference: bool,
+}
+
+impl Args {
+    pub fn parse() -> Args {
+        let mut positional = Vec::new();
+        let mut session_file = None;
+        let mut offline = false;
+        let mut year = None;
+        let mut all = false;
+        let mut normalize = false;
+        let mut raw = false;
+        let mut input = None;
+        let mut size = None;
+        let mut seed = None;
+        let mut output = None;
+        let mut reference = false;
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--session-file" => {
+                    let path = args.next().expect("--session-file requires a path");
+                    session_file = Some(PathBuf::from(path));
+                }
+                "--offline" => offline = true,
+                "--year" => {
+                    let value = args.next().expect("--year requires a value");
+                    year = Some(value.parse().expect("--year must be a number"));
+                }
+                "--all" => all = true,
+                "--normalize" => normalize = true,
+                "--raw" => raw = true,
+                "--reference" => reference = true,
+                "--input" => {
+                    let path = args.next().expect("--input requires a path");
+                    input = Some(PathBuf::from(path));
+                }
+                "--size" => size = Some(args.next().expect("--size requires a value")),
+                "--seed" => {
+                    let value = args.next().expect("--seed requires a value");
+                    seed = Some(value.parse().expect("--seed must be a number"));
+                }
+                "--output" => {
+                    let path = args.next().expect("--output requires a path");
+                    output = Some(PathBuf::from(path));
+                }
+                _ => positional.push(arg),
+            }
+        }
+        let mut positional = positional.into_iter();
+        let command = match positional.next().unwrap_or_default().as_str() {
+            "leaderboard" => Command::Leaderboard(
+                positional
+                    .next()
+                    .map(|id| id.parse().expect("Leaderboard ID must be a number")),
+            ),
+            "fetch" if all => Command::Fetch((1..=25).collect()),
+            "fetch" => {
+                let days = positional
+                    .map(|day| {
+                        day.trim_start_matches("day")
+                            .parse()
+                            .expect("Days to fetch must be numbers")
+                    })
+                    .collect::<Vec<u32>>();
+                if days.is_empty() {
+                    panic!("Please pass days to fetch or --all");
+                }
+                Command::Fetch(days)
+            }
+            "gen" => Command::Generate(
+                positional
+                    .next()
+                    .expect("Please pass a day to generate input for")
+                    .trim_start_matches("day")
+                    .parse()
+                    .expect("Day to generate must be a number"),
+            ),
+            day => Command::Solve(String::from(day)),
+        };
+        Args {
+            command,
+            session_file,
+            offline,
+            year,
+            normalize,
+            raw,
+            input,
+            size,
+            seed,
+            output,
+            reference,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 extern crate utils;
 
-use utils::ChallengeSolver;
+use utils::{parse, ChallengeSolver};
 
 pub struct Solver {
     input: Vec<Monkey>,
@@ -25,23 +25,12 @@ struct Monkey {
 
 impl Solver {
     pub fn new(input: String) -> Solver {
-        let mut prev_char = '\0';
-        let input = input
-            .split(|c| {
-                let mut result = false;
-                if prev_char == '\n' && c == '\n' {
-                    result = true;
-                }
-                prev_char = c;
-                result
-            })
+        let input = parse::paragraphs(&input)
+            .into_iter()
             .map(|monkey_string| {
                 let mut monkey_string = monkey_string.lines();
                 monkey_string.next();
-                let item_list = monkey_string
-                    .next()
-                    .unwrap()
-                    .split_once(':')
+                let item_list = parse::key_value(monkey_string.next().unwrap())
                     .unwrap()
                     .1
                     .split(',')
@@ -1,108 +1,63 @@
 extern crate utils;
 
-use std::collections::VecDeque;
-
-use utils::plane::{Boundary, Direction};
+use utils::plane::{Coordinates, Direction, Grid};
+use utils::search;
 use utils::ChallengeSolver;
 
 pub struct Solver {
-    input: Vec<Vec<(usize, usize)>>,
-    start: usize,
-    finish: usize,
+    input: Grid<usize>,
+    start_pos: Coordinates,
+    finish_pos: Coordinates,
 }
 
 impl Solver {
     pub fn new(input: String) -> Solver {
-        let input = input
-            .lines()
-            .map(|line| {
-                let line = line.trim();
-                line.chars()
-                    .map(|c| {
-                        if c >= 'a' {
-                            (c as usize - 'a' as usize, usize::MAX)
-                        } else {
-                            (c as usize, usize::MAX)
-                        }
-                    })
-                    .collect::<Vec<(usize, usize)>>()
-            })
-            .collect::<Vec<Vec<(usize, usize)>>>();
+        let map = Grid::parse(&input, |c| c);
+        let start_pos = map.find(|c| *c == 'S').unwrap();
+        let finish_pos = map.find(|c| *c == 'E').unwrap();
+        let input = map.map(|c| match c {
+            'S' => 0,
+            'E' => 'z' as usize - 'a' as usize,
+            _ => *c as usize - 'a' as usize,
+        });
         Solver {
             input,
-            start: 'S' as usize,
-            finish: 'E' as usize,
-        }
-    }
-
-    fn get_pos(&self, value: usize) -> (usize, usize) {
-        for i in 0..self.input.len() {
-            for j in 0..self.input[i].len() {
-                if self.input[i][j].0 == value {
-                    return (i, j);
-                }
-            }
+            start_pos,
+            finish_pos,
         }
-        return (usize::MAX, usize::MAX);
     }
 
-    fn calculate_distances(
-        map: &mut Vec<Vec<(usize, usize)>>,
-        start_pos: (usize, usize),
-        finish_pos: (usize, usize),
-    ) {
-        let boundary = Boundary::new_array_boundary(map[0].len(), map.len());
-        map[start_pos.0][start_pos.1] = (0, 0);
-        map[finish_pos.0][finish_pos.1].0 = 'z' as usize - 'a' as usize;
-        let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
-        queue.push_back(start_pos);
-        while map[finish_pos.0][finish_pos.1].1 == usize::MAX {
-            if queue.is_empty() {
-                return;
-            }
-            let (cur_i, cur_j) = queue.pop_front().unwrap();
-            Direction::get_basic_directions().iter().for_each(|dir| {
-                if let Some((i, j)) = boundary.move_array_iterator((cur_i, cur_j), dir) {
-                    if map[i][j].0 <= map[cur_i][cur_j].0 + 1
-                        && map[i][j].1 > map[cur_i][cur_j].1 + 1
-                    {
-                        map[i][j].1 = map[cur_i][cur_j].1 + 1;
-                        queue.push_back((i, j));
-                    }
-                }
-            });
-        }
+    fn get_shortest_path_len(&self, starting_positions: Vec<Coordinates>) -> usize {
+        let path = search::bfs(
+            starting_positions,
+            |pos| {
+                let height = *self.input.get(pos).unwrap();
+                self.input
+                    .get_neighbours(pos, Direction::get_basic_directions())
+                    .filter(|(_, _, next_height)| **next_height <= height + 1)
+                    .map(|(_, next_pos, _)| next_pos)
+                    .collect::<Vec<Coordinates>>()
+            },
+            |pos| *pos == self.finish_pos,
+        )
+        .expect("There's no path to the best signal");
+        path.get_cost()
     }
 }
 
 impl ChallengeSolver for Solver {
     fn get_part_a_result(&self) -> String {
-        let start_pos = self.get_pos(self.start);
-        let finish_pos = self.get_pos(self.finish);
-        let mut map = self.input.clone();
-        Solver::calculate_distances(&mut map, start_pos, finish_pos);
-        let result = map[finish_pos.0][finish_pos.1].1;
+        let result = self.get_shortest_path_len(vec![self.start_pos]);
         String::from(result.to_string())
     }
     fn get_part_b_result(&self) -> String {
-        let finish_pos = self.get_pos(self.finish);
-        // so basically, 'b's are only in the second column and entire first column is filled with 'a'
-        // this quite limits the initial choice
         let starting_positions = self
             .input
             .iter()
-            .enumerate()
-            .map(|(i, _)| (i, 0))
-            .collect::<Vec<(usize, usize)>>();
-        let results = starting_positions
-            .iter()
-            .map(|pos| {
-                let mut map = self.input.clone();
-                Solver::calculate_distances(&mut map, *pos, finish_pos);
-                return map[finish_pos.0][finish_pos.1].1;
-            })
-            .collect::<Vec<usize>>();
-        let result = results.iter().min().unwrap();
+            .filter(|(_, height)| **height == 0)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<Coordinates>>();
+        let result = self.get_shortest_path_len(starting_positions);
         String::from(result.to_string())
     }
 }
@@ -1,161 +1,168 @@
-// abandon all hope ye who enter here
-
 extern crate utils;
 
-use std::cmp::Ordering;
-
-use utils::ChallengeSolver;
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use nom::{
+    branch::alt,
+    character::complete::{char, u32},
+    combinator::{all_consuming, cut, map, opt},
+    multi::many0,
+    sequence::{pair, preceded, terminated},
+    Finish, IResult,
+};
+use serde::{Deserialize, Serialize};
+use utils::{
+    parse::{self, ParseError},
+    ChallengeSolver,
+};
 
 pub struct Solver {
-    input: Vec<(String, String)>,
+    input: Vec<(Packet, Packet)>,
 }
 
-impl Solver {
-    pub fn new(input: String) -> Solver {
-        let mut prev_char = '\0';
-        let input = input
-            .split(|c| {
-                let mut result = false;
-                if prev_char == '\n' && c == '\n' {
-                    result = true;
+// packets are valid json, so serde can read and write them as they are
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    // once a list is opened, failures are reported where they happen instead of backtracking to the `[`
+    fn parse(input: &str) -> IResult<&str, Packet> {
+        let items = map(
+            opt(pair(
+                Packet::parse,
+                many0(preceded(char(','), cut(Packet::parse))),
+            )),
+            |items| match items {
+                Some((first, mut rest)) => {
+                    rest.insert(0, first);
+                    rest
                 }
-                prev_char = c;
-                result
-            })
-            .map(|pair| {
-                let (left_packet, right_packet) = pair.split_once('\n').unwrap();
-                (String::from(left_packet), String::from(right_packet))
-            })
-            .collect::<Vec<(String, String)>>();
-        Solver { input }
+                None => vec![],
+            },
+        );
+        alt((
+            map(u32, Packet::Integer),
+            map(
+                preceded(char('['), cut(terminated(items, char(']')))),
+                Packet::List,
+            ),
+        ))(input)
     }
+}
 
-    fn prepare_packet(packet: &str) -> Vec<&str> {
-        packet
-            .split_inclusive(&[']', '['][..])
-            .flat_map(|entry| {
-                entry
-                    .split(',')
-                    .flat_map(|entrier| {
-                        // i can't i just can't this is not possible
-                        // please revoke my script kiddie license
-                        // yes im' goign mad
-                        if entrier == "" {
-                            vec![]
-                        } else if entrier == "]" {
-                            vec![entrier]
-                        } else if entrier.contains(']') {
-                            vec![entrier.split_once(']').unwrap().0, "]"]
-                        } else {
-                            vec![entrier]
-                        }
-                    })
-                    .collect::<Vec<&str>>()
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        all_consuming(Packet::parse)(s)
+            .finish()
+            .map(|(_, packet)| packet)
+            .map_err(|e| {
+                let column = s[..s.len() - e.input.len()].chars().count() + 1;
+                let message = match e.input.chars().next() {
+                    Some(c) => format!("unexpected `{}`", c),
+                    None => String::from("unexpected end of packet"),
+                };
+                ParseError::new(1, column, message)
             })
-            .collect::<Vec<&str>>()
     }
+}
 
-    fn inject_lists(left_packet: &mut Vec<&str>, right_packet: &mut Vec<&str>) {
-        let mut i = 0;
-        while i < left_packet.len() && i < right_packet.len() {
-            if left_packet[i] != "[" && right_packet[i] == "[" {
-                if left_packet[i] == "]" {
-                    break;
-                }
-                left_packet.insert(i, "[");
-                left_packet.insert(i + 2, "]");
-            } else if left_packet[i] == "[" && right_packet[i] != "[" {
-                if right_packet[i] == "]" {
-                    break;
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::Integer(n) => write!(f, "{}", n),
+            Packet::List(packets) => {
+                write!(f, "[")?;
+                for (i, packet) in packets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", packet)?;
                 }
-                right_packet.insert(i, "[");
-                right_packet.insert(i + 2, "]");
+                write!(f, "]")
             }
-            i += 1
         }
     }
+}
 
-    fn is_in_right_order(left_packet: &str, right_packet: &str) -> (bool, Option<usize>) {
-        let mut left_packet = Solver::prepare_packet(left_packet);
-        let mut right_packet = Solver::prepare_packet(right_packet);
-        Solver::inject_lists(&mut left_packet, &mut right_packet);
-        if let Some((i, left, right)) = left_packet
-            .iter()
-            .zip(right_packet.iter())
-            .enumerate()
-            .find_map(|(i, (left, right))| {
-                if left == right {
-                    None
-                } else {
-                    Some((i, *left, *right))
-                }
-            })
-        {
-            if right == "]" {
-                (false, Some(i))
-            } else if left == "]" {
-                (true, Some(i))
-            } else {
-                let left = left.parse::<usize>().unwrap();
-                let right = right.parse::<usize>().unwrap();
-                if left < right {
-                    (true, Some(i))
-                } else {
-                    (false, Some(i))
-                }
+// an integer compared with a list acts as a list holding just that integer,
+// so 1 and [[1]] are equal even though they don't look the same
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+            (Packet::Integer(left), Packet::List(right)) => {
+                [Packet::Integer(*left)][..].cmp(&right[..])
+            }
+            (Packet::List(left), Packet::Integer(right)) => {
+                left[..].cmp(&[Packet::Integer(*right)][..])
             }
-        } else {
-            (true, None)
         }
     }
 }
 
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl Solver {
+    pub fn new(input: String) -> Solver {
+        let input = parse::parse_paragraphs(&input, |pair| {
+            let packets = parse::parse_lines(pair, |line| line.parse::<Packet>())?;
+            match <[Packet; 2]>::try_from(packets) {
+                Ok([left_packet, right_packet]) => Ok((left_packet, right_packet)),
+                Err(_) => Err(ParseError::new(
+                    1,
+                    1,
+                    String::from("expected a pair of packets"),
+                )),
+            }
+        })
+        .unwrap();
+        Solver { input }
+    }
+}
+
 impl ChallengeSolver for Solver {
     fn get_part_a_result(&self) -> String {
-        let result =
-            self.input
-                .iter()
-                .enumerate()
-                .fold(0, |acc, (i, (left_packet, right_packet))| {
-                    let (result, _) = Solver::is_in_right_order(left_packet, right_packet);
-                    if result {
-                        return acc + i + 1;
-                    }
-                    acc
-                });
+        let result = self
+            .input
+            .iter()
+            .enumerate()
+            .filter(|(_, (left_packet, right_packet))| left_packet < right_packet)
+            .map(|(i, _)| i + 1)
+            .sum::<usize>();
         String::from(result.to_string())
     }
     fn get_part_b_result(&self) -> String {
-        let (left_packets, right_packets): (Vec<String>, Vec<String>) =
-            self.input.iter().cloned().unzip();
-        let mut packets = left_packets
+        let dividers = ["[[2]]", "[[6]]"].map(|divider| divider.parse::<Packet>().unwrap());
+        // no need to sort, a divider's position is just the number of packets that go before it
+        let packets = self
+            .input
             .iter()
-            .chain(right_packets.iter())
-            .cloned()
-            .collect::<Vec<String>>();
-        packets.push(String::from("[[2]]"));
-        packets.push(String::from("[[6]]"));
-        packets.sort_by(|left_packet, right_packet| {
-            let (result, _) =
-                Solver::is_in_right_order(left_packet.as_str(), right_packet.as_str());
-            if result {
-                return Ordering::Less;
-            } else {
-                return Ordering::Greater;
-            }
-        });
-        let get_decoder = |decoder: &str| {
-            packets
-                .iter()
-                .enumerate()
-                .find(|(_, packet)| *packet == decoder)
-                .unwrap()
-                .0
-                + 1
-        };
-        let first_decoder_pos = get_decoder("[[2]]");
-        let second_decoder_pos = get_decoder("[[6]]");
-        let result = first_decoder_pos * second_decoder_pos;
+            .flat_map(|(left_packet, right_packet)| [left_packet, right_packet])
+            .chain(dividers.iter())
+            .collect::<Vec<&Packet>>();
+        let result = dividers
+            .iter()
+            .map(|divider| packets.iter().filter(|packet| **packet < divider).count() + 1)
+            .product::<usize>();
         String::from(result.to_string())
     }
 }
@@ -163,6 +170,7 @@ impl ChallengeSolver for Solver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     fn get_input() -> String {
         String::from(
@@ -225,81 +233,86 @@ mod tests {
     }
 
     #[test]
-    fn packet_cleaning_test() {
-        let result = vec!["[", "10", "13", "[", "3", "11", "]", "42", "[", "]", "]"];
-
-        let answer = Solver::prepare_packet("[10,13,[3,11],42,[]]");
+    fn order_test_single_list() {
+        let list1 = "[1,1,3,1,1]".parse::<Packet>().unwrap();
+        let list2 = "[1,1,5,1,1]".parse::<Packet>().unwrap();
 
-        assert_eq!(&result, &answer);
+        assert_eq!(list1.cmp(&list2), Ordering::Less);
     }
 
     #[test]
-    fn list_injecting_test_should_be_equal() {
-        let mut list1 = vec!["[", "[", "10", "]", "]"];
-        let mut list2 = vec!["[", "10", "]"];
-
-        Solver::inject_lists(&mut list1, &mut list2);
+    fn order_test_lists_only() {
+        let list1 = "[[[]]]".parse::<Packet>().unwrap();
+        let list2 = "[[]]".parse::<Packet>().unwrap();
 
-        assert_eq!(&list1, &list2);
+        assert_eq!(list1.cmp(&list2), Ordering::Greater);
     }
 
     #[test]
-    fn list_injecting_test_actual_example() {
-        let mut list1 = vec!["[", "[", "1", "]", "[", "2", "3", "4", "]", "]"];
-        let mut list2 = vec!["[", "[", "1", "]", "4", "]"];
-
-        let expected_list1 = list1.clone();
-        Solver::inject_lists(&mut list1, &mut list2);
-        let expected_list2 = vec!["[", "[", "1", "]", "[", "4", "]", "]"];
+    fn order_test_added_list() {
+        let list1 = "[[1],[2,3,4]]".parse::<Packet>().unwrap();
+        let list2 = "[[1],4]".parse::<Packet>().unwrap();
 
-        assert_eq!(&list1, &expected_list1);
-        assert_eq!(&list2, &expected_list2)
+        assert_eq!(list1.cmp(&list2), Ordering::Less);
+        assert_eq!("[[10]]".parse::<Packet>().unwrap(), Packet::Integer(10));
     }
 
     #[test]
-    fn list_injecting_test_lists_only() {
-        let mut list1 = vec!["[", "[", "[", "]", "]", "]"];
-        let mut list2 = vec!["[", "[", "]", "]"];
+    fn parse_error_test() {
+        let error = "[1,[2,x]]".parse::<Packet>().unwrap_err();
 
-        let expected_list1 = list1.clone();
-        let expected_list2 = list2.clone();
-        Solver::inject_lists(&mut list1, &mut list2);
-
-        assert_eq!(&list1, &expected_list1);
-        assert_eq!(&list2, &expected_list2);
+        assert_eq!(
+            (error.get_column(), error.get_message()),
+            (7, "unexpected `x`")
+        );
     }
 
-    #[test]
-    fn order_test_single_list() {
-        let list1 = "[1,1,3,1,1]";
-        let list2 = "[1,1,5,1,1]";
-
-        let (result, last_i) = Solver::is_in_right_order(list1, list2);
-
-        assert_eq!(&result, &true);
-        assert_eq!(&last_i, &Some(3));
+    fn get_packet_strategy() -> impl Strategy<Value = Packet> {
+        // small numbers, so that a lot of the packets end up being equal up to some point
+        let integer = (0u32..11).prop_map(Packet::Integer);
+        integer.prop_recursive(4, 32, 4, |packet| {
+            prop::collection::vec(packet, 0..4).prop_map(Packet::List)
+        })
     }
 
-    #[test]
-    fn order_test_lists_only() {
-        let list1 = "[[[]]]";
-        let list2 = "[[]]";
+    proptest! {
+        #[test]
+        fn order_is_total(
+            a in get_packet_strategy(),
+            b in get_packet_strategy(),
+            c in get_packet_strategy(),
+        ) {
+            prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+            prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+        }
 
-        let (result, last_i) = Solver::is_in_right_order(list1, list2);
+        #[test]
+        fn sorting_is_consistent(mut packets in prop::collection::vec(get_packet_strategy(), 0..20)) {
+            packets.sort();
 
-        assert_eq!(&result, &false);
-        assert_eq!(&last_i, &Some(2));
-    }
-
-    #[test]
-    fn order_test_added_list() {
-        let list1 = "[[1],[2,3,4]]";
-        let list2 = "[[1],4]";
+            for (i, left) in packets.iter().enumerate() {
+                for right in packets[i..].iter() {
+                    prop_assert!(left <= right);
+                }
+            }
+        }
 
-        let (result, last_i) = Solver::is_in_right_order(list1, list2);
+        #[test]
+        fn display_round_trip(packet in get_packet_strategy()) {
+            let packet_string = packet.to_string();
 
-        assert_eq!(&result, &true);
-        assert_eq!(&last_i, &Some(5));
+            prop_assert_eq!(packet_string.parse::<Packet>().unwrap().to_string(), packet_string.clone());
+            prop_assert_eq!(serde_json::to_string(&packet).unwrap(), packet_string.clone());
+            prop_assert_eq!(
+                serde_json::from_str::<Packet>(&packet_string).unwrap().to_string(),
+                packet_string
+            );
+        }
     }
 
     #[test]
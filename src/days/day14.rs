@@ -3,6 +3,7 @@ extern crate utils;
 use std::{collections::HashSet, mem::swap};
 
 use utils::{
+    parse,
     plane::{Boundary, Direction},
     ChallengeSolver,
 };
@@ -19,7 +20,7 @@ impl Solver {
         let input = input
             .lines()
             .flat_map(|line| {
-                let coords = line.trim().split("->").collect::<Vec<&str>>();
+                let coords = parse::split_arrows(line.trim());
                 let mut i = 0;
                 let mut rocks: HashSet<(isize, isize)> = HashSet::new();
                 while i < coords.len() - 1 {
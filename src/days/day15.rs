@@ -1,47 +1,59 @@
 extern crate utils;
 
-use std::{
-    cmp::Ordering,
-    collections::{HashMap, HashSet},
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use utils::{
+    interval::{Interval, IntervalSet},
+    parse::{self, Pattern},
+    plane::Coordinates,
+    ChallengeSolver,
 };
-use utils::{plane::Coordinates, ChallengeSolver};
 
 pub struct Solver {
     input: Vec<(Coordinates, Coordinates)>,
+    row: isize,
+    area_size: isize,
 }
 
 impl Solver {
     pub fn new(input: String) -> Solver {
-        let input = input
-            .lines()
-            .map(|line| {
-                let numbers = line
-                    .trim()
-                    .split(&[' ', '=', ',', ':'])
-                    .filter_map(|word| word.parse::<isize>().ok())
-                    .collect::<Vec<isize>>();
-                return (
-                    Coordinates::new(numbers[0], numbers[1]),
-                    Coordinates::new(numbers[2], numbers[3]),
-                );
-            })
-            .collect::<Vec<(Coordinates, Coordinates)>>();
-        Solver { input }
+        Solver::from_reader(input.as_bytes())
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Solver {
+        let pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+        let input = parse::parse_lines_from_reader(reader, |line| {
+            let numbers = pattern.extract::<isize>(line)?;
+            Ok((
+                Coordinates::new(numbers[0], numbers[1]),
+                Coordinates::new(numbers[2], numbers[3]),
+            ))
+        })
+        .unwrap();
+        Solver {
+            input,
+            row: 2000000,
+            area_size: 4000000,
+        }
+    }
+
+    // the puzzle's example (and smaller generated inputs) use a different row and search area
+    #[cfg(test)]
+    pub(crate) fn with_area(mut self, row: isize, area_size: isize) -> Solver {
+        self.row = row;
+        self.area_size = area_size;
+        self
     }
 
-    fn get_ranges_for_each_y(&self) -> HashMap<isize, Vec<(isize, isize)>> {
-        let mut ranges = HashMap::new();
+    fn get_ranges_for_each_y(&self) -> HashMap<isize, IntervalSet> {
+        let mut ranges: HashMap<isize, IntervalSet> = HashMap::new();
         self.input.iter().for_each(|(sensor, beacon)| {
-            let distance = (sensor.get_x().abs_diff(beacon.get_x())
-                + sensor.get_y().abs_diff(beacon.get_y())) as isize;
+            let distance = sensor.manhattan_distance(beacon) as isize;
             let mut insert_range = |y: isize, length: isize| {
-                if !ranges.contains_key(&y) {
-                    ranges.insert(y, vec![]);
-                }
-                ranges
-                    .get_mut(&y)
-                    .unwrap()
-                    .push((sensor.get_x() - length, sensor.get_x() + length + 1));
+                ranges.entry(y).or_default().insert(
+                    Interval::inclusive(sensor.get_x() - length, sensor.get_x() + length).unwrap(),
+                );
             };
             for i in 0..distance {
                 insert_range(sensor.get_y() - distance + i, i);
@@ -50,35 +62,9 @@ impl Solver {
                 insert_range(sensor.get_y() + i, distance - i);
             }
         });
-        ranges.iter_mut().for_each(|mut entry| {
-            Solver::merge_ranges(&mut entry.1);
-        });
         return ranges;
     }
 
-    fn merge_ranges(ranges: &mut Vec<(isize, isize)>) {
-        ranges.sort_by(|a, b| {
-            if a == b {
-                return Ordering::Equal;
-            }
-            if a.0 < b.0 || (a.0 == b.0 && a.1 < b.1) {
-                return Ordering::Less;
-            }
-            return Ordering::Greater;
-        });
-        let mut i = 0;
-        while i + 1 < ranges.len() {
-            if ranges[i + 1].1 < ranges[i].1 {
-                ranges.remove(i + 1);
-            } else if ranges[i + 1].0 <= ranges[i].1 {
-                ranges[i].1 = ranges[i + 1].1;
-                ranges.remove(i + 1);
-            } else {
-                i += 1;
-            }
-        }
-    }
-
     fn get_num_of_special_fields(&self) -> HashMap<isize, isize> {
         let mut field_nums = HashMap::new();
         let mut calculated_fields: HashSet<(isize, isize)> = HashSet::new();
@@ -102,31 +88,25 @@ impl Solver {
 
     fn get_num_of_covered_position(
         y: isize,
-        ranges: &HashMap<isize, Vec<(isize, isize)>>,
+        ranges: &HashMap<isize, IntervalSet>,
         special_field_num: &HashMap<isize, isize>,
     ) -> isize {
         let zero: isize = 0;
-        return ranges
-            .get(&y)
-            .unwrap()
-            .iter()
-            .fold(0, |acc, range| acc + range.1 - range.0)
-            - special_field_num.get(&y).unwrap_or(&zero);
+        return ranges.get(&y).unwrap().len() as isize - special_field_num.get(&y).unwrap_or(&zero);
     }
 
-    fn get_hole(ranges: &HashMap<isize, Vec<(isize, isize)>>) -> Coordinates {
+    fn get_hole(ranges: &HashMap<isize, IntervalSet>, area_size: isize) -> Coordinates {
         ranges
             .iter()
             .filter_map(|(y, ranges)| {
-                if *y < 0 || *y >= 4000000 || ranges.len() == 1 {
+                if *y < 0 || *y > area_size {
                     return None;
                 }
-                for i in 0..ranges.len() - 1 {
-                    if ranges[i + 1].0 - ranges[i].1 == 1 {
-                        return Some(Coordinates::new(ranges[i].1, *y));
-                    }
-                }
-                None
+                ranges
+                    .gaps()
+                    .iter()
+                    .find(|gap| gap.len() == 1 && (0..=area_size).contains(&gap.get_start()))
+                    .map(|gap| Coordinates::new(gap.get_start(), *y))
             })
             .next()
             .unwrap()
@@ -137,12 +117,12 @@ impl ChallengeSolver for Solver {
     fn get_part_a_result(&self) -> String {
         let ranges = self.get_ranges_for_each_y();
         let special_field_num = self.get_num_of_special_fields();
-        let result = Solver::get_num_of_covered_position(2000000, &ranges, &special_field_num);
+        let result = Solver::get_num_of_covered_position(self.row, &ranges, &special_field_num);
         String::from(result.to_string())
     }
     fn get_part_b_result(&self) -> String {
         let ranges = self.get_ranges_for_each_y();
-        let result_coords = Solver::get_hole(&ranges);
+        let result_coords = Solver::get_hole(&ranges, self.area_size);
         let result = result_coords.get_x() * 4000000 + result_coords.get_y();
         String::from(result.to_string())
     }
@@ -197,12 +177,16 @@ mod tests {
 
     #[test]
     fn ranges_test() {
-        let mut ranges = vec![(11, 12), (0, 7), (-3, 0), (7, 8), (2, 5), (4, 10), (11, 13)];
-        let expected_ranges = vec![(-3, 10), (11, 13)];
-
-        Solver::merge_ranges(&mut ranges);
-
-        assert_eq!(&expected_ranges, &ranges);
+        let ranges = [(11, 12), (0, 7), (-3, 0), (7, 8), (2, 5), (4, 10), (11, 13)]
+            .iter()
+            .filter_map(|(start, end)| Interval::new(*start, *end))
+            .collect::<IntervalSet>();
+        let expected_ranges = vec![
+            Interval::new(-3, 10).unwrap(),
+            Interval::new(11, 13).unwrap(),
+        ];
+
+        assert_eq!(&expected_ranges, ranges.get_intervals());
     }
 
     #[test]
@@ -211,9 +195,17 @@ mod tests {
         let ranges = solver.get_ranges_for_each_y();
         let result = Coordinates::new(14, 11);
 
-        let answer = Solver::get_hole(&ranges);
+        let answer = Solver::get_hole(&ranges, 20);
 
         assert_eq!(&result.get_x(), &answer.get_x());
         assert_eq!(&result.get_y(), &answer.get_y());
     }
+
+    #[test]
+    fn example_area_test() {
+        let solver = Solver::new(get_input()).with_area(10, 20);
+
+        assert_eq!(&solver.get_part_a_result(), "26");
+        assert_eq!(&solver.get_part_b_result(), "56000011");
+    }
 }
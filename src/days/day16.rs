@@ -2,7 +2,7 @@ extern crate utils;
 
 use std::collections::{HashMap, HashSet};
 
-use utils::ChallengeSolver;
+use utils::{graph::Graph, ChallengeSolver};
 
 struct Node {
     room: usize,
@@ -57,53 +57,36 @@ impl Solver {
                 }
             })
             .collect::<Vec<Node>>();
-        Solver::calculate_distances(&mut input);
-        Solver::remove_irrelevant_distances(&mut input);
+        let first_node = *room_name_map.get("AA").unwrap();
+        Solver::calculate_distances(&mut input, first_node);
         Solver {
             input,
-            first_node: *room_name_map.get("AA").unwrap(),
+            first_node,
             room_to_vec_pos_map,
         }
     }
 
-    fn init_distances(nodes: &mut Vec<Node>) {
-        for i in 0..nodes.len() {
-            for j in 0..nodes.len() {
-                let j_room = nodes[j].room.clone();
-                let mut distance = usize::MAX / 2; // avoid overflow
-                if nodes[i].connections.contains(&j_room) {
-                    distance = 1
-                }
-                nodes[i].distances.insert(j_room, distance);
-            }
-        }
-    }
-
-    fn calculate_distances(nodes: &mut Vec<Node>) {
-        Solver::init_distances(nodes);
-        for k in 0..nodes.len() {
-            let k_room = nodes[k].room;
-            for i in 0..nodes.len() {
-                for j in 0..nodes.len() {
-                    let j_room = nodes[j].room;
-                    let cur_dist = nodes[i].distances[&j_room];
-                    let calc_dist = nodes[i].distances[&k_room] + nodes[k].distances[&j_room];
-                    *nodes[i].distances.get_mut(&j_room).unwrap() =
-                        std::cmp::min(cur_dist, calc_dist);
-                }
-            }
-        }
-    }
-
-    fn remove_irrelevant_distances(nodes: &mut Vec<Node>) {
-        for i in 0..nodes.len() {
-            for j in 0..nodes.len() {
-                if nodes[j].flow_rate == 0 || i == j {
-                    let j_room = nodes[j].room;
-                    nodes[i].distances.remove(&j_room);
-                }
-            }
-        }
+    fn calculate_distances(nodes: &mut [Node], first_room: usize) {
+        let mut graph = Graph::new_directed();
+        nodes.iter().for_each(|node| {
+            graph.add_node(node.room);
+            node.connections.iter().for_each(|connection| {
+                graph.add_edge(node.room, *connection, 1);
+            });
+        });
+        let flow_rates = nodes
+            .iter()
+            .map(|node| (node.room, node.flow_rate))
+            .collect::<HashMap<usize, usize>>();
+        // only the valves worth opening and the starting room matter
+        let graph = graph.contract(|room| flow_rates[room] > 0 || *room == first_room);
+        nodes.iter_mut().for_each(|node| {
+            node.distances = graph
+                .get_edges(&node.room)
+                .filter(|(room, _)| flow_rates[room] > 0)
+                .map(|(room, distance)| (*room, distance))
+                .collect();
+        });
     }
 }
 
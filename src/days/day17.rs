@@ -1,8 +1,7 @@
 extern crate utils;
 
-use std::collections::{VecDeque, HashSet};
-
 use utils::{
+    cycle::CycleDetector,
     plane::{Boundary, Direction},
     ChallengeSolver,
 };
@@ -14,7 +13,6 @@ pub struct Solver {
 #[derive(Debug, Clone)]
 struct Rock {
     points: Vec<(usize, usize)>,
-    middle_pos: (usize, usize),
 }
 
 impl Rock {
@@ -57,78 +55,60 @@ impl Rock {
                 panic!("Invalid rock variant");
             }
         }
-        let mut rock = Rock {
-            points,
-            middle_pos: (0, 0),
-        };
-        rock._update_middle_pos();
-        return rock;
-    }
-
-    fn _update_middle_pos(&mut self) {
-        let pos_sum = self
-            .points
-            .iter()
-            .fold((0, 0), |acc, pos| (acc.0 + pos.0, acc.1 + pos.1));
-        self.middle_pos = (pos_sum.0 / self.points.len(), pos_sum.1 / self.points.len());
+        Rock { points }
     }
 
-    fn can_push(&self, boundary: &Boundary, direction: &Direction, rocks: &VecDeque<Rock>) -> bool {
-        let can_move = self
-            .points
-            .iter()
-            .filter_map(|point| boundary.move_array_iterator(*point, direction))
-            .count()
-            == self.points.len();
-        if can_move {
-            let mut moved_rock = self.clone();
-            moved_rock.commit_move(boundary, direction);
-            return rocks
-                .iter()
-                .filter_map(|other_rock| moved_rock._is_colliding(other_rock))
-                .next()
-                .is_none();
-        }
-        return false;
+    fn can_push(&self, boundary: &Boundary, direction: &Direction, chamber: &[[bool; 7]]) -> bool {
+        self.points.iter().all(|point| {
+            boundary
+                .move_array_iterator(*point, direction)
+                .is_some_and(|(y, x)| !is_taken(chamber, y, x))
+        })
     }
 
     fn commit_move(&mut self, boundary: &Boundary, direction: &Direction) {
         self.points
             .iter_mut()
             .for_each(|point| *point = boundary.move_array_iterator(*point, direction).unwrap());
-        self._update_middle_pos();
     }
 
     fn get_highest_y(&self) -> usize {
         self.points.iter().max_by(|a, b| a.0.cmp(&b.0)).unwrap().0
     }
+}
 
-    fn _is_colliding(&self, other: &Rock) -> Option<()> {
-        let manhattan_distance = self.middle_pos.0.abs_diff(other.middle_pos.0)
-            + self.middle_pos.1.abs_diff(other.middle_pos.1);
-        if manhattan_distance < 5 {
-            if let Some(_) = self
-                .points
-                .iter()
-                .filter_map(|self_point| {
-                    other
-                        .points
-                        .iter()
-                        .filter_map(|other_point| {
-                            if self_point == other_point {
-                                return Some(());
-                            }
-                            None
-                        })
-                        .next()
-                })
-                .next()
-            {
-                return Some(());
-            }
+fn is_taken(chamber: &[[bool; 7]], y: usize, x: usize) -> bool {
+    chamber.get(y).is_some_and(|row| row[x])
+}
+
+// empty spots that a falling rock can still get to, one bitmask per row going down from the top,
+// rocks only move sideways and down, so nothing below them will ever matter again
+fn get_reachable_spots(chamber: &[[bool; 7]], highest_y: usize) -> Vec<u8> {
+    let mut spots: Vec<u8> = vec![];
+    let mut queue = (0..7)
+        .map(|x| (highest_y + 1, x))
+        .collect::<Vec<(usize, usize)>>();
+    while let Some((y, x)) = queue.pop() {
+        let depth = highest_y + 1 - y;
+        if y == 0
+            || is_taken(chamber, y, x)
+            || spots.get(depth).is_some_and(|row| row & 1 << x != 0)
+        {
+            continue;
+        }
+        if spots.len() <= depth {
+            spots.resize(depth + 1, 0);
+        }
+        spots[depth] |= 1 << x;
+        queue.push((y - 1, x));
+        if x > 0 {
+            queue.push((y, x - 1));
+        }
+        if x < 6 {
+            queue.push((y, x + 1));
         }
-        None
     }
+    spots
 }
 
 impl Solver {
@@ -147,22 +127,29 @@ impl Solver {
         Solver { input }
     }
 
-    fn run_simulation(&self, num_of_rocks: usize) -> usize {
-        let mut rocks = VecDeque::new();
-        let vec_len = 30;
-        rocks.reserve(vec_len);
+    // the next rock only depends on its shape, the wind and the part of the chamber it can reach,
+    // so the heights start repeating once that combination shows up for the second time
+    pub(crate) fn run_simulation(&self, num_of_rocks: usize) -> usize {
+        let mut chamber: Vec<[bool; 7]> = vec![];
         let boundary = Boundary::new(0, 1, 7, isize::MAX);
         let mut highest_y = 0;
-        let mut wind = self.input.iter().cycle();
+        let mut heights = vec![highest_y];
+        let mut wind_index = 0;
+        let mut cycle_detector = CycleDetector::new();
         for i in 0..num_of_rocks {
+            let fingerprint = (i % 5, wind_index, get_reachable_spots(&chamber, highest_y));
+            if let Some(cycle) = cycle_detector.push(fingerprint) {
+                return cycle.extrapolate(num_of_rocks, |n| heights[n]);
+            }
             let mut rock = Rock::new(highest_y + 4, 2, i % 5);
             let mut is_pushed_down = true;
             while is_pushed_down {
-                let direction = wind.next().unwrap();
-                if rock.can_push(&boundary, direction, &rocks) {
+                let direction = &self.input[wind_index];
+                wind_index = (wind_index + 1) % self.input.len();
+                if rock.can_push(&boundary, direction, &chamber) {
                     rock.commit_move(&boundary, direction);
                 }
-                if rock.can_push(&boundary, &Direction::Up, &rocks) {
+                if rock.can_push(&boundary, &Direction::Up, &chamber) {
                     rock.commit_move(&boundary, &Direction::Up)
                 } else {
                     is_pushed_down = false;
@@ -172,8 +159,11 @@ impl Solver {
             if rock_highest_y > highest_y {
                 highest_y = rock_highest_y;
             }
-            rocks.push_front(rock);
-            rocks.truncate(vec_len);
+            heights.push(highest_y);
+            if chamber.len() <= highest_y {
+                chamber.resize(highest_y + 1, [false; 7]);
+            }
+            rock.points.iter().for_each(|(y, x)| chamber[*y][*x] = true);
         }
         return highest_y;
     }
@@ -186,54 +176,8 @@ impl ChallengeSolver for Solver {
         String::from(result.to_string())
     }
     fn get_part_b_result(&self) -> String {
-        // it's super slow, but can probably find solution for any input
-        // it should find candidate in max 2 loop iterations, but it will still take a while
-        // due to amount of candidates (well, this is literally bruteforce)
-        // in sample data the cycle started to appear after going through
-        // the wind list 2 times [(2*len)/4], so i sticked to that
-        // though it's way too high for actual input
-        // decreasing it for real input case doesn't save much time
-        // it's still got to iterate over all possible candidates once
-        // and it seems that candidates need to be divisible by 5
-        // maybe it's a coincidence but that also matches amount of shape variants
-        let mut candidates = (5..self.input.len()).filter(|num| num % 5 == 0).collect::<HashSet<usize>>();
-        for i in self.input.len()/2..self.input.len() {
-            let mut new_candidates = HashSet::new();
-            let cycle_offset = self.run_simulation(i);
-            for candidate in candidates.clone() {
-                let result = self.run_simulation(i + candidate);
-                let result2 = self.run_simulation(i + candidate * 2);
-                if result2 - result == result - cycle_offset {
-                    println!("found possible candidate for {i} {candidate}");
-                    new_candidates.insert(candidate);
-                }
-            }
-            candidates = candidates.intersection(&new_candidates).cloned().collect();
-            println!("remaining candidates: {candidates:?}");
-            let mut are_multiples = true;
-            let lowest = candidates.iter().min().unwrap();
-            for candidate in candidates.clone() {
-                if candidate % lowest != 0 {
-                    are_multiples = false;
-                }
-            }
-            if are_multiples {
-                break;
-            }
-            else if candidates.len() == 1 {
-                break;
-            }
-        }
-        let cycle_length = candidates.iter().next().unwrap();
-        println!("final cycle length: {cycle_length}");
-        let before_cycle = self.run_simulation(self.input.len()/2);
-        let after_cycle = self.run_simulation(self.input.len()/2 + cycle_length);
-        let diff = after_cycle - before_cycle;
-
-        let cycle_remainder = (1000000000000 - self.input.len()/2) % cycle_length;
-        let cycled_iters = (1000000000000 - self.input.len()/2) - cycle_remainder;
-        let num_of_cycles = cycled_iters / cycle_length;            
-        let result = diff * num_of_cycles + self.run_simulation(self.input.len()/2 + cycle_remainder);
+        let num_of_rocks = 1000000000000;
+        let result = self.run_simulation(num_of_rocks);
         String::from(result.to_string())
     }
 }
@@ -265,4 +209,15 @@ mod tests {
 
         assert_eq!(&result, &answer);
     }
+
+    #[test]
+    fn deep_fall_test() {
+        // some rocks fall a long way down past the ones that landed before them
+        let solver = Solver::new(String::from(">><<<<>>>>>"));
+        let result = 46;
+
+        let answer = solver.run_simulation(40);
+
+        assert_eq!(&result, &answer);
+    }
 }
@@ -1,11 +1,8 @@
 extern crate utils;
 
-use std::{
-    collections::{hash_map::DefaultHasher, HashSet},
-    hash::{Hash, Hasher},
-};
+use std::io::BufRead;
 
-use utils::ChallengeSolver;
+use utils::{input, optimize, ChallengeSolver};
 
 pub struct Solver {
     input: Vec<Blueprint>,
@@ -22,7 +19,7 @@ struct Blueprint {
     max_ore_cost: usize,
 }
 
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Savepoint {
     time: usize,
     ore: usize,
@@ -44,17 +41,25 @@ impl Savepoint {
         self.geode += self.geobots;
     }
 
-    fn get_hash(&self) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        self.hash(&mut hasher);
-        hasher.finish()
+    // geodes collected by the end if no more bots get built
+    fn get_guaranteed_geodes(&self, time_limit: usize) -> usize {
+        self.geode + self.geobots * (time_limit - self.time)
+    }
+
+    // geodes collected by the end if a geode bot got built every remaining turn
+    fn get_max_possible_geodes(&self, time_limit: usize) -> usize {
+        let time_left = time_limit - self.time;
+        self.get_guaranteed_geodes(time_limit) + time_left * time_left.saturating_sub(1) / 2
     }
 }
 
 impl Solver {
     pub fn new(input: String) -> Solver {
-        let input = input
-            .lines()
+        Solver::from_reader(input.as_bytes())
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Solver {
+        let input = input::read_lines(reader)
             .map(|line| {
                 let nums = line
                     .trim()
@@ -88,21 +93,12 @@ impl Solver {
             geode: 0,
             geobots: 0,
         };
-        let mut visited = HashSet::with_capacity(100000);
-        let mut stack = Vec::with_capacity(100000);
-        stack.push(initial_savepoint);
-        let mut max_geodes = 0;
-        while !stack.is_empty() {
-            let mut cur_save = stack.pop().unwrap();
-            let cur_save_hash = cur_save.get_hash();
-            if visited.contains(&cur_save_hash) {
-                continue;
-            }
+        let get_next_savepoints = |cur_save: &Savepoint| {
+            let mut cur_save = *cur_save;
+            let mut next_saves = vec![];
             if cur_save.time == time_limit {
-                max_geodes = std::cmp::max(max_geodes, cur_save.geode);
-                continue;
+                return next_saves;
             }
-            visited.insert(cur_save_hash);
             // try building each from most to least complex
             // note to self: you can't build more than one bot in a turn, so it's pointless to overproduce resources
             if cur_save.ore >= blueprint.geobot_ore_cost
@@ -113,8 +109,8 @@ impl Solver {
                 new_save.ore -= blueprint.geobot_ore_cost;
                 new_save.obsidian -= blueprint.geobot_obsidian_cost;
                 new_save.geobots += 1;
-                stack.push(new_save);
-                continue; // i mean if we can do it, we totally should, as it is the ultimate victory goal, right?
+                next_saves.push(new_save);
+                return next_saves; // i mean if we can do it, we totally should, as it is the ultimate victory goal, right?
             }
             if cur_save.obsibots < blueprint.geobot_obsidian_cost
                 && cur_save.ore >= blueprint.obsibot_ore_cost
@@ -125,7 +121,7 @@ impl Solver {
                 new_save.ore -= blueprint.obsibot_ore_cost;
                 new_save.clay -= blueprint.obsibot_clay_cost;
                 new_save.obsibots += 1;
-                stack.push(new_save);
+                next_saves.push(new_save);
             }
             if cur_save.claybots < blueprint.obsibot_clay_cost
                 && cur_save.ore >= blueprint.claybot_ore_cost
@@ -134,7 +130,7 @@ impl Solver {
                 new_save.generate_resources();
                 new_save.ore -= blueprint.claybot_ore_cost;
                 new_save.claybots += 1;
-                stack.push(new_save)
+                next_saves.push(new_save)
             }
             if cur_save.orebots < blueprint.max_ore_cost
                 && cur_save.ore >= blueprint.orebot_ore_cost
@@ -143,35 +139,43 @@ impl Solver {
                 new_save.generate_resources();
                 new_save.ore -= blueprint.orebot_ore_cost;
                 new_save.orebots += 1;
-                stack.push(new_save);
+                next_saves.push(new_save);
             }
             cur_save.generate_resources();
-            stack.push(cur_save);
-        }
-        max_geodes
+            next_saves.push(cur_save);
+            next_saves
+        };
+        optimize::maximize(
+            [initial_savepoint],
+            get_next_savepoints,
+            |save| save.get_guaranteed_geodes(time_limit),
+            |save| save.get_max_possible_geodes(time_limit),
+        )
+        .unwrap()
+        .get_value()
+    }
+
+    pub(crate) fn get_max_geodes(&self, time_limit: usize, num_of_blueprints: usize) -> Vec<usize> {
+        self.input
+            .iter()
+            .take(num_of_blueprints)
+            .map(|blueprint| Solver::run_simulation(blueprint, time_limit))
+            .collect()
     }
 }
 
 impl ChallengeSolver for Solver {
     fn get_part_a_result(&self) -> String {
-        let time_limit = 24;
-        let max_geodes = self
-            .input
+        let max_geodes = self.get_max_geodes(24, self.input.len());
+        let result = max_geodes
             .iter()
             .enumerate()
-            .map(|(i, blueprint)| Solver::run_simulation(blueprint, time_limit) * (i + 1))
-            .collect::<Vec<usize>>();
-        let result = max_geodes.iter().sum::<usize>();
+            .map(|(i, geodes)| geodes * (i + 1))
+            .sum::<usize>();
         String::from(result.to_string())
     }
     fn get_part_b_result(&self) -> String {
-        let time_limit = 32;
-        let max_geodes = self
-            .input
-            .iter()
-            .take(3)
-            .map(|blueprint| Solver::run_simulation(blueprint, time_limit))
-            .collect::<Vec<usize>>();
+        let max_geodes = self.get_max_geodes(32, 3);
         let result = max_geodes.iter().product::<usize>();
         String::from(result.to_string())
     }
@@ -1,8 +1,8 @@
 extern crate utils;
 
-use std::collections::LinkedList;
+use std::{collections::LinkedList, io::BufRead};
 
-use utils::ChallengeSolver;
+use utils::{input, ChallengeSolver};
 
 pub struct Solver {
     input: LinkedList<(usize, isize)>,
@@ -10,8 +10,11 @@ pub struct Solver {
 
 impl Solver {
     pub fn new(input: String) -> Solver {
-        let input = input
-            .lines()
+        Solver::from_reader(input.as_bytes())
+    }
+
+    pub fn from_reader<R: BufRead>(reader: R) -> Solver {
+        let input = input::read_lines(reader)
             .enumerate()
             .map(|(i, line)| (i, line.trim().parse::<isize>().unwrap()))
             .collect::<LinkedList<(usize, isize)>>();
@@ -1,50 +1,31 @@
 extern crate utils;
 
-use std::{
-    collections::{hash_map::DefaultHasher, HashSet, VecDeque},
-    hash::{Hash, Hasher},
-};
+use std::collections::HashSet;
 
 use utils::{
     plane::{Boundary, Direction},
-    ChallengeSolver,
+    search, ChallengeSolver,
 };
 
 pub struct Solver {
-    input: Vec<Blizzard>,
+    blizzards: HashSet<((usize, usize), Direction)>,
     boundary: Boundary,
     destination: (usize, usize),
 }
 
-#[derive(Clone, Copy)]
-struct Blizzard {
-    pos: (usize, usize),
-    dir: Direction,
-}
-
 #[derive(Debug, Hash, Clone, Copy)]
 struct Savepoint {
     player_pos: (isize, isize),
     time: usize,
 }
 
-impl Savepoint {
-    fn get_hash(&self, time_cycle: usize) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        let mut savepoint = self.clone();
-        savepoint.time = savepoint.time % time_cycle;
-        savepoint.hash(&mut hasher);
-        hasher.finish()
-    }
-}
-
 impl Solver {
     pub fn new(input: String) -> Solver {
         let y_size = input.lines().count() - 2;
         let x_size = input.lines().nth(0).unwrap().len() - 2;
         let destination = (y_size - 1, x_size - 1);
         let boundary = Boundary::new_array_boundary(x_size, y_size);
-        let input = input
+        let blizzards = input
             .lines()
             .enumerate()
             .flat_map(|(y, row)| {
@@ -52,40 +33,47 @@ impl Solver {
                     .chars()
                     .enumerate()
                     .filter_map(|(x, c)| {
-                        let pos = (y - 1, x - 1);
-                        match c {
-                            '>' => Some(Blizzard {
-                                pos,
-                                dir: Direction::Right,
-                            }),
-                            'v' => Some(Blizzard {
-                                pos,
-                                dir: Direction::Down,
-                            }),
-                            '<' => Some(Blizzard {
-                                pos,
-                                dir: Direction::Left,
-                            }),
-                            '^' => Some(Blizzard {
-                                pos,
-                                dir: Direction::Up,
-                            }),
-                            _ => None,
-                        }
+                        let dir = match c {
+                            '>' => Direction::Right,
+                            'v' => Direction::Down,
+                            '<' => Direction::Left,
+                            '^' => Direction::Up,
+                            _ => return None,
+                        };
+                        Some(((y - 1, x - 1), dir))
                     })
-                    .collect::<Vec<Blizzard>>()
+                    .collect::<Vec<((usize, usize), Direction)>>()
             })
-            .collect::<Vec<Blizzard>>();
+            .collect::<HashSet<((usize, usize), Direction)>>();
         Solver {
-            input,
+            blizzards,
             boundary,
             destination,
         }
     }
 
-    fn run_simulation(&self, is_part_a: bool) -> usize {
-        let mut blizzards = self.input.clone();
-        let mut current_time = 0;
+    // blizzards move in straight lines, so it's enough to look where each kind would have started from
+    fn is_free(&self, (i, j): (isize, isize), time: usize) -> bool {
+        if i < 0 || i > self.destination.0 as isize {
+            return true;
+        }
+        let time = time as isize;
+        let is_blizzard_from = |pos: (isize, isize), dir: Direction| {
+            let (i, j) = self.boundary.wrap(pos);
+            self.blizzards.contains(&((i as usize, j as usize), dir))
+        };
+        !is_blizzard_from((i, j - time), Direction::Right)
+            && !is_blizzard_from((i, j + time), Direction::Left)
+            && !is_blizzard_from((i - time, j), Direction::Down)
+            && !is_blizzard_from((i + time, j), Direction::Up)
+    }
+
+    fn get_shortest_path(
+        &self,
+        destination: (usize, usize),
+        start_pos: (isize, isize),
+        start_time: usize,
+    ) -> usize {
         let possible_directions = [
             Direction::None,
             Direction::Up,
@@ -94,78 +82,40 @@ impl Solver {
             Direction::Left,
         ];
         let cycle = (self.destination.0 + 1) * (self.destination.1 + 1);
-        let move_blizzards = |blizzards: &mut Vec<Blizzard>| {
-            blizzards.iter_mut().for_each(|bliz| {
-                if let Some(pos) = self.boundary.move_array_iterator(bliz.pos, &bliz.dir) {
-                    bliz.pos = pos;
-                } else {
-                    bliz.pos = self.boundary.wrap_array_iterator(bliz.pos, &bliz.dir);
-                }
-            });
-        };
-        let mut get_shortest_path = |destination: (usize, usize), start_pos: (isize, isize)| {
-            let mut queue: VecDeque<Savepoint> = VecDeque::new();
-            let mut advance_time = true;
-            let mut seen: HashSet<u64> = HashSet::new();
-            queue.push_back(Savepoint {
+        let destination = (destination.0 as isize, destination.1 as isize);
+        let path = search::bfs_by_key(
+            [Savepoint {
                 player_pos: start_pos,
-                time: 0,
-            });
-            'advance_time: while advance_time {
-                current_time += 1;
-                move_blizzards(&mut blizzards);
-
-                while let Some(savepoint) = queue.pop_front() {
-                    if savepoint.time == current_time || !advance_time {
-                        queue.push_front(savepoint);
-                        continue 'advance_time;
-                    }
-                    possible_directions.iter().for_each(|dir| {
-                        if let Some(pos) = self.boundary.move_iterator(savepoint.player_pos, dir) {
-                            let pos_u = (pos.0 as usize, pos.1 as usize);
-                            let is_move_possible = blizzards
-                                .iter()
-                                .filter_map(|bliz| {
-                                    if pos_u == bliz.pos {
-                                        return Some(());
-                                    }
-                                    None
-                                })
-                                .next()
-                                .is_none();
-                            if is_move_possible {
-                                if pos_u == destination {
-                                    advance_time = false;
-                                }
-                                let savepoint = Savepoint {
-                                    player_pos: pos,
-                                    time: current_time,
-                                };
-                                let hash = savepoint.get_hash(cycle);
-                                if !seen.contains(&hash) {
-                                    seen.insert(hash);
-                                    queue.push_back(savepoint);
-                                }
-                            }
-                        }
-                    });
-                }
-            }
-            current_time += 1;
-            move_blizzards(&mut blizzards);
-        };
-        if is_part_a {
-            let start_pos = (-1, 0);
-            get_shortest_path(self.destination, start_pos);
-        } else {
-            let top_left_start = (-1, 0);
-            let top_left_dest = (0, 0);
-            let bot_right_start = (self.destination.0 as isize + 1, self.destination.1 as isize);
-            get_shortest_path(self.destination, top_left_start);
-            get_shortest_path(top_left_dest, bot_right_start);
-            get_shortest_path(self.destination, top_left_start);
-        }
+                time: start_time,
+            }],
+            |savepoint| {
+                possible_directions
+                    .iter()
+                    .filter_map(|dir| self.boundary.move_iterator(savepoint.player_pos, dir))
+                    .map(|pos| Savepoint {
+                        player_pos: pos,
+                        time: savepoint.time + 1,
+                    })
+                    .filter(|savepoint| self.is_free(savepoint.player_pos, savepoint.time))
+                    .collect::<Vec<Savepoint>>()
+            },
+            |savepoint| savepoint.player_pos == destination,
+            |savepoint| (savepoint.player_pos, savepoint.time % cycle),
+        )
+        .expect("There's no way through the blizzards");
+        // one more minute to step out of the valley
+        path.get_goal().time + 1
+    }
 
+    fn run_simulation(&self, is_part_a: bool) -> usize {
+        let top_left_start = (-1, 0);
+        let top_left_dest = (0, 0);
+        let bot_right_start = (self.destination.0 as isize + 1, self.destination.1 as isize);
+        let mut current_time = self.get_shortest_path(self.destination, top_left_start, 0);
+        if !is_part_a {
+            current_time = self.get_shortest_path(top_left_dest, bot_right_start, current_time);
+            current_time = self.get_shortest_path(self.destination, top_left_start, current_time);
+        }
         current_time
     }
 }
@@ -43,7 +43,7 @@ impl Solver {
         }
     }
 
-    fn encode_snafu(mut num: isize) -> String {
+    pub(crate) fn encode_snafu(mut num: isize) -> String {
         let mut snafu = String::new();
         while num > 0 {
             let rem = num % 5;
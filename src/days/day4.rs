@@ -1,11 +1,9 @@
 extern crate utils;
 
-use std::collections::HashSet;
-
-use utils::ChallengeSolver;
+use utils::{interval::Interval, ChallengeSolver};
 
 pub struct Solver {
-    input: Vec<(HashSet<u32>, HashSet<u32>)>,
+    input: Vec<(Interval, Interval)>,
 }
 
 impl Solver {
@@ -14,13 +12,15 @@ impl Solver {
             .lines()
             .map(|line| {
                 let (first_range, second_range) = line.trim().split_once(',').unwrap();
-                fn build_into_set(range: &str) -> HashSet<u32> {
+                fn build_interval(range: &str) -> Interval {
                     let (range_start, range_end) = range.split_once('-').unwrap();
-                    return HashSet::from_iter(
-                        range_start.parse::<u32>().unwrap()..range_end.parse::<u32>().unwrap() + 1,
-                    );
+                    return Interval::inclusive(
+                        range_start.parse().unwrap(),
+                        range_end.parse().unwrap(),
+                    )
+                    .unwrap();
                 }
-                return (build_into_set(first_range), build_into_set(second_range));
+                return (build_interval(first_range), build_interval(second_range));
             })
             .collect();
         Solver { input }
@@ -30,9 +30,9 @@ impl Solver {
 impl ChallengeSolver for Solver {
     fn get_part_a_result(&self) -> String {
         let result = self.input.iter().fold(0, |acc, x| {
-            let (set_a, set_b) = x;
-            let intersection_size = set_a.intersection(set_b).count();
-            if intersection_size == set_a.len() || intersection_size == set_b.len() {
+            let (interval_a, interval_b) = x;
+            if interval_a.contains_interval(interval_b) || interval_b.contains_interval(interval_a)
+            {
                 return acc + 1;
             }
             acc
@@ -41,9 +41,8 @@ impl ChallengeSolver for Solver {
     }
     fn get_part_b_result(&self) -> String {
         let result = self.input.iter().fold(0, |acc, x| {
-            let (set_a, set_b) = x;
-            let intersection_size = set_a.intersection(set_b).count();
-            if intersection_size > 0 {
+            let (interval_a, interval_b) = x;
+            if interval_a.overlaps(interval_b) {
                 return acc + 1;
             }
             acc
@@ -0,0 +1,18 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// groups of snack calories, one elf per group
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_elves = size.pick(10, 250, 25000);
+    (0..num_of_elves)
+        .map(|_| {
+            (0..rng.get_usize(1..16))
+                .map(|_| rng.get_usize(1000..60000).to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+        + "\n"
+}
@@ -0,0 +1,29 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// the crt has exactly 240 pixels and the solver draws one per cycle, so the program has to take exactly 240 cycles
+pub fn generate(rng: &mut Rng, _size: Size) -> String {
+    let mut cycles = 0;
+    let mut x: isize = 1;
+    let mut program = vec![];
+    while cycles < 240 {
+        if cycles + 2 <= 240 && rng.get_bool(0.6) {
+            let mut value = rng.get_isize(-15..16);
+            if !(-1..=41).contains(&(x + value)) || value == 0 {
+                value = if x < 20 {
+                    rng.get_isize(1..6)
+                } else {
+                    -rng.get_isize(1..6)
+                };
+            }
+            x += value;
+            program.push(format!("addx {}", value));
+            cycles += 2;
+        } else {
+            program.push(String::from("noop"));
+            cycles += 1;
+        }
+    }
+    program.join("\n") + "\n"
+}
@@ -0,0 +1,120 @@
+use utils::random::Rng;
+
+use super::Size;
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<usize>,
+    operator: char,
+    // None stands for old
+    parameter: Option<usize>,
+    test_val: usize,
+    success_target: usize,
+    failure_target: usize,
+}
+
+impl Monkey {
+    fn inspect(&self, item: usize) -> Option<usize> {
+        let parameter = self.parameter.unwrap_or(item);
+        match self.operator {
+            '+' => item.checked_add(parameter),
+            _ => item.checked_mul(parameter),
+        }
+    }
+}
+
+// part B works modulo the product of the tests, squaring anything below it has to fit in usize,
+// so monkeys get distinct small primes and there can't be more of them than there are primes below 25
+fn generate_monkeys(rng: &mut Rng, num_of_monkeys: usize, num_of_items: usize) -> Vec<Monkey> {
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let squaring_monkey = rng.get_usize(0..num_of_monkeys);
+    (0..num_of_monkeys)
+        .map(|i| {
+            let (operator, parameter) = if i == squaring_monkey {
+                ('*', None)
+            } else if rng.get_bool(0.3) {
+                ('*', Some(rng.get_usize(2..20)))
+            } else {
+                ('+', Some(rng.get_usize(1..9)))
+            };
+            let mut targets = (0..num_of_monkeys)
+                .filter(|target| *target != i)
+                .collect::<Vec<usize>>();
+            rng.shuffle(&mut targets);
+            Monkey {
+                items: (0..rng.get_usize(1..num_of_items + 1))
+                    .map(|_| rng.get_usize(50..100))
+                    .collect(),
+                operator,
+                parameter,
+                test_val: primes[i],
+                success_target: targets[0],
+                failure_target: targets[1],
+            }
+        })
+        .collect()
+}
+
+// part A doesn't keep the worry levels in check, so it's simulated here to throw out monkeys that would overflow
+fn is_part_a_safe(monkeys: &[Monkey]) -> bool {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<Vec<usize>>>();
+    for _ in 0..20 {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let item = match monkey.inspect(item) {
+                    Some(item) => item / 3,
+                    None => return false,
+                };
+                let target = if item % monkey.test_val == 0 {
+                    monkey.success_target
+                } else {
+                    monkey.failure_target
+                };
+                items[target].push(item);
+            }
+        }
+    }
+    true
+}
+
+fn render_monkey(i: usize, monkey: &Monkey) -> String {
+    let parameter = monkey
+        .parameter
+        .map_or(String::from("old"), |parameter| parameter.to_string());
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        i,
+        monkey
+            .items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<String>>()
+            .join(", "),
+        monkey.operator,
+        parameter,
+        monkey.test_val,
+        monkey.success_target,
+        monkey.failure_target
+    )
+}
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_monkeys = size.pick(4, 8, 9);
+    let num_of_items = size.pick(4, 8, 30);
+    let monkeys = loop {
+        let monkeys = generate_monkeys(rng, num_of_monkeys, num_of_items);
+        if is_part_a_safe(&monkeys) {
+            break monkeys;
+        }
+    };
+    monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, monkey)| render_monkey(i, monkey))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
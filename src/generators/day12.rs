@@ -0,0 +1,45 @@
+use utils::random::Rng;
+
+use super::{get_lowercase_letter, Size};
+
+// a walk from S on the left edge to E that only goes right, up or down (never back the way it came),
+// its heights climb from a to z slowly enough to be walkable, the rest of the map is noise
+fn get_path(rng: &mut Rng, height: usize, width: usize) -> Vec<(usize, usize)> {
+    let mut pos = (rng.get_usize(0..height), 0);
+    let mut path = vec![pos];
+    let mut vertical_direction = 0;
+    while pos.1 < width - 1 {
+        let can_go_up = pos.0 > 0 && vertical_direction != 1;
+        let can_go_down = pos.0 < height - 1 && vertical_direction != -1;
+        let roll = rng.get_usize(0..4);
+        if roll == 0 && can_go_up {
+            pos.0 -= 1;
+            vertical_direction = -1;
+        } else if roll == 1 && can_go_down {
+            pos.0 += 1;
+            vertical_direction = 1;
+        } else {
+            pos.1 += 1;
+            vertical_direction = 0;
+        }
+        path.push(pos);
+    }
+    path
+}
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let (height, width) = size.pick((5, 30), (41, 160), (200, 1000));
+    let mut map = (0..height)
+        .map(|_| (0..width).map(|_| get_lowercase_letter(rng)).collect())
+        .collect::<Vec<Vec<char>>>();
+    let path = get_path(rng, height, width);
+    path.iter().enumerate().for_each(|(i, (y, x))| {
+        map[*y][*x] = (b'a' + (i * 25 / (path.len() - 1)) as u8) as char;
+    });
+    let (start, end) = (path[0], path[path.len() - 1]);
+    map[start.0][start.1] = 'S';
+    map[end.0][end.1] = 'E';
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
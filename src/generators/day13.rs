@@ -0,0 +1,41 @@
+use utils::random::Rng;
+
+use super::Size;
+use crate::days::day13::Packet;
+
+fn generate_packet(rng: &mut Rng, depth: usize) -> Packet {
+    if depth > 0 && rng.get_bool(0.4) {
+        Packet::List(
+            (0..rng.get_usize(0..5))
+                .map(|_| generate_packet(rng, depth - 1))
+                .collect(),
+        )
+    } else {
+        Packet::Integer(rng.get_usize(0..11) as u32)
+    }
+}
+
+// pairs are never equal, as the puzzle wouldn't have an answer for them,
+// and neither packet is the same as one of the dividers added in part B
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_pairs = size.pick(5, 150, 15000);
+    let dividers = ["[[2]]", "[[6]]"].map(|divider| divider.parse::<Packet>().unwrap());
+    let get_packet = |rng: &mut Rng| loop {
+        let packet = Packet::List(
+            (0..rng.get_usize(0..6))
+                .map(|_| generate_packet(rng, 4))
+                .collect(),
+        );
+        if !dividers.contains(&packet) {
+            return packet;
+        }
+    };
+    let mut pairs = vec![];
+    while pairs.len() < num_of_pairs {
+        let (left, right) = (get_packet(rng), get_packet(rng));
+        if left != right {
+            pairs.push(format!("{}\n{}\n", left, right));
+        }
+    }
+    pairs.join("\n")
+}
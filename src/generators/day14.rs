@@ -0,0 +1,41 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// rock paths made of horizontal and vertical segments under the sand source at 500,0
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_paths = size.pick(3, 150, 1500);
+    let (width, depth) = size.pick((10, 12), (60, 170), (300, 800));
+    (0..num_of_paths)
+        .map(|_| {
+            let mut pos = (
+                rng.get_isize(500 - width..500 + width),
+                rng.get_isize(2..depth),
+            );
+            let mut points = vec![pos];
+            let mut is_horizontal = rng.get_bool(0.5);
+            for _ in 0..rng.get_usize(1..5) {
+                let len = rng.get_isize(1..8);
+                let sign = if rng.get_bool(0.5) { 1 } else { -1 };
+                if is_horizontal {
+                    pos.0 += sign * len;
+                } else {
+                    pos.1 = (pos.1 + sign * len).max(2);
+                }
+                if points.last() != Some(&pos) {
+                    points.push(pos);
+                }
+                is_horizontal = !is_horizontal;
+            }
+            if points.len() == 1 {
+                points.push((pos.0 + 1, pos.1));
+            }
+            points
+                .iter()
+                .map(|(x, y)| format!("{},{}", x, y))
+                .collect::<Vec<String>>()
+                .join(" -> ")
+                + "\n"
+        })
+        .collect()
+}
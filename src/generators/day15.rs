@@ -0,0 +1,81 @@
+use utils::random::Rng;
+
+use super::Size;
+
+const AREA_SIZE: isize = 4000000;
+
+fn get_distance(a: (isize, isize), b: (isize, isize)) -> isize {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+// the solver looks at row 2000000 and for the hole in 0..=4000000, so the sizes are fixed,
+// the hole stays in the middle to keep the sensors (and the solver's memory use) smaller
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let hole = (
+        rng.get_isize(AREA_SIZE * 3 / 8..AREA_SIZE * 5 / 8),
+        rng.get_isize(AREA_SIZE * 3 / 8..AREA_SIZE * 5 / 8),
+    );
+    generate_around(rng, size, hole, AREA_SIZE)
+}
+
+// four sensors sit diagonally around the hole at (±k, ±k) and reach 2k - 1 far, which covers the square of side k
+// between the hole and each of them, their beacons are on the outer tips, away from everything else,
+// as long as every k is less than twice as big as the others no beacon ends up inside another sensor's range,
+// bigger inputs add sensors on the way from a big sensor to its beacon, they're in its range so the hole doesn't move
+pub fn generate_around(
+    rng: &mut Rng,
+    size: Size,
+    hole: (isize, isize),
+    area_size: isize,
+) -> String {
+    let min_k = [hole.0, hole.1, area_size - hole.0, area_size - hole.1]
+        .into_iter()
+        .max()
+        .unwrap()
+        + 1;
+    let mut pairs = [(1, 1), (1, -1), (-1, 1), (-1, -1)]
+        .into_iter()
+        .map(|(dx, dy)| {
+            let k = rng.get_isize(min_k..min_k + min_k / 4 + 1);
+            let sensor = (hole.0 + dx * k, hole.1 + dy * k);
+            let beacon = if rng.get_bool(0.5) {
+                (sensor.0 + dx * (2 * k - 1), sensor.1)
+            } else {
+                (sensor.0, sensor.1 + dy * (2 * k - 1))
+            };
+            (sensor, beacon)
+        })
+        .collect::<Vec<((isize, isize), (isize, isize))>>();
+    let beacons = pairs
+        .iter()
+        .map(|pair| pair.1)
+        .collect::<Vec<(isize, isize)>>();
+    let num_of_sensors = size.pick(4, 15, 40);
+    while pairs.len() < num_of_sensors {
+        let (big_sensor, beacon) = pairs[rng.get_usize(0..4)];
+        let distance = get_distance(big_sensor, beacon);
+        let step = rng.get_isize(distance / 2..distance);
+        let sensor = (
+            big_sensor.0 + (beacon.0 - big_sensor.0).signum() * step,
+            big_sensor.1 + (beacon.1 - big_sensor.1).signum() * step,
+        );
+        let radius = get_distance(sensor, beacon);
+        let is_beacon_closest = beacons
+            .iter()
+            .filter(|other| **other != beacon)
+            .all(|other| get_distance(sensor, *other) > radius);
+        if is_beacon_closest && !pairs.iter().any(|pair| pair.0 == sensor) {
+            pairs.push((sensor, beacon));
+        }
+    }
+    rng.shuffle(&mut pairs);
+    pairs
+        .iter()
+        .map(|(sensor, beacon)| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            )
+        })
+        .collect()
+}
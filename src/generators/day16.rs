@@ -0,0 +1,67 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// like in the real input the valves with any flow are spread out, joined by corridors of broken ones,
+// AA is always broken and everything can be reached from it
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_working_valves = size.pick(6, 10, 15);
+    let mut flow_rates = vec![0];
+    flow_rates.extend((0..num_of_working_valves).map(|_| rng.get_usize(1..26)));
+    let mut tunnels: Vec<Vec<usize>> = vec![vec![]; flow_rates.len()];
+    let mut connect = |a: usize, b: usize, flow_rates: &mut Vec<usize>, rng: &mut Rng| {
+        let mut previous = a;
+        for _ in 0..rng.get_usize(1..4) {
+            let valve = tunnels.len();
+            flow_rates.push(0);
+            tunnels.push(vec![previous]);
+            tunnels[previous].push(valve);
+            previous = valve;
+        }
+        tunnels[previous].push(b);
+        tunnels[b].push(previous);
+    };
+    // a random tree of corridors keeps everything reachable, the extra ones make loops
+    for i in 1..=num_of_working_valves {
+        connect(i, rng.get_usize(0..i), &mut flow_rates, rng);
+    }
+    for _ in 0..num_of_working_valves / 3 {
+        let a = rng.get_usize(0..num_of_working_valves + 1);
+        let b = rng.get_usize(0..num_of_working_valves + 1);
+        if a != b {
+            connect(a, b, &mut flow_rates, rng);
+        }
+    }
+    let mut names = vec![String::from("AA")];
+    while names.len() < flow_rates.len() {
+        let name = (0..2)
+            .map(|_| (b'A' + rng.get_usize(0..26) as u8) as char)
+            .collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let mut order = (0..flow_rates.len()).collect::<Vec<usize>>();
+    rng.shuffle(&mut order);
+    order
+        .iter()
+        .map(|i| {
+            let targets = tunnels[*i]
+                .iter()
+                .map(|j| names[*j].as_str())
+                .collect::<Vec<&str>>();
+            let tunnel_text = if targets.len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}\n",
+                names[*i],
+                flow_rates[*i],
+                tunnel_text,
+                targets.join(", ")
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,12 @@
+use utils::random::Rng;
+
+use super::Size;
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let len = size.pick(40, 1000, 10091);
+    let mut jets = (0..len)
+        .map(|_| if rng.get_bool(0.5) { '<' } else { '>' })
+        .collect::<String>();
+    jets.push('\n');
+    jets
+}
@@ -0,0 +1,21 @@
+use std::collections::HashSet;
+
+use utils::random::Rng;
+
+use super::Size;
+
+// cubes are denser in the middle of the space, so there are some pockets of air trapped inside
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_cubes = size.pick(15, 300, 2800);
+    let side = size.pick(5, 10, 21) as isize;
+    let mut cubes = HashSet::new();
+    let mut output = String::new();
+    while cubes.len() < num_of_cubes {
+        let mut get_coordinate = || (rng.get_isize(0..side) + rng.get_isize(0..side)) / 2 + 1;
+        let cube = (get_coordinate(), get_coordinate(), get_coordinate());
+        if cubes.insert(cube) {
+            output += &format!("{},{},{}\n", cube.0, cube.1, cube.2);
+        }
+    }
+    output
+}
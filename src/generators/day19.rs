@@ -0,0 +1,23 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// costs stay in the same ranges as in the real input, the solver's run time depends a lot on them
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_blueprints = size.pick(2, 10, 30);
+    (1..=num_of_blueprints)
+        .map(|i| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                i,
+                rng.get_usize(2..5),
+                rng.get_usize(2..5),
+                rng.get_usize(2..5),
+                rng.get_usize(4..21),
+                rng.get_usize(2..5),
+                rng.get_usize(7..21),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,16 @@
+use utils::random::Rng;
+
+use super::Size;
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_rounds = size.pick(10, 2500, 250000);
+    (0..num_of_rounds)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
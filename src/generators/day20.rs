@@ -0,0 +1,20 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// there has to be exactly one 0, the grove coordinates are counted from it,
+// lengths that divide 1000 would make all of them point back at it
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let len = size.pick(7, 997, 5000);
+    let mut numbers = (1..len)
+        .map(|_| loop {
+            let n = rng.get_isize(-10000..10001);
+            if n != 0 {
+                return n;
+            }
+        })
+        .collect::<Vec<isize>>();
+    numbers.push(0);
+    rng.shuffle(&mut numbers);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use utils::random::Rng;
+
+use super::{get_lowercase_letter, Size};
+
+// the solver works on unsigned numbers, so every monkey has to yell a non-negative value both with the original humn
+// and with the one that makes root's sides equal, and every division has to be exact
+const MAX_VALUE: usize = 10000000000000;
+
+struct Monkeys<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl<'a> Monkeys<'a> {
+    fn get_name(&mut self) -> String {
+        loop {
+            let name = (0..4)
+                .map(|_| get_lowercase_letter(self.rng))
+                .collect::<String>();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn add_operation(&mut self, name: &str, lhs: &str, operator: char, rhs: &str) {
+        self.lines
+            .push(format!("{}: {} {} {}", name, lhs, operator, rhs));
+    }
+
+    // a monkey (and everything below it) that doesn't depend on humn
+    fn add_constant(&mut self, value: usize, depth: usize) -> String {
+        let name = self.get_name();
+        if depth == 0 || self.rng.get_bool(0.3) {
+            self.lines.push(format!("{}: {}", name, value));
+            return name;
+        }
+        let divisor = (2..20).find(|d| value > 0 && value.is_multiple_of(*d));
+        let (lhs, operator, rhs) = match (self.rng.get_usize(0..4), divisor) {
+            (1, _) => {
+                let rhs = self.rng.get_usize(1..20);
+                (value + rhs, '-', rhs)
+            }
+            (2, Some(d)) => (value / d, '*', d),
+            (3, _) if value < MAX_VALUE / 10 => {
+                let d = self.rng.get_usize(2..10);
+                (value * d, '/', d)
+            }
+            _ => {
+                let lhs = self.rng.get_usize(0..value + 1);
+                (lhs, '+', value - lhs)
+            }
+        };
+        let lhs = self.add_constant(lhs, depth - 1);
+        let rhs = self.add_constant(rhs, depth - 1);
+        self.add_operation(&name, &lhs, operator, &rhs);
+        name
+    }
+
+    // a monkey on the way from root to humn, the values are the ones it yells in part A and in part B
+    fn add_humn_path(&mut self, name: String, values: (usize, usize), length: usize, depth: usize) {
+        if length == 0 {
+            self.lines.push(format!("humn: {}", values.0));
+            return;
+        }
+        let (a, b) = values;
+        let gcd = get_gcd(a, b);
+        let divisors = (2..50).filter(|d| gcd.is_multiple_of(*d)).collect::<Vec<usize>>();
+        let child = if length == 1 {
+            String::from("humn")
+        } else {
+            self.get_name()
+        };
+        let is_child_lhs = self.rng.get_bool(0.5);
+        let (operator, constant, child_values) = loop {
+            match self.rng.get_usize(0..5) {
+                0 if a.min(b) > 1 => {
+                    let c = self.rng.get_usize(1..a.min(b));
+                    break ('+', c, (a - c, b - c));
+                }
+                1 if is_child_lhs && a.max(b) < MAX_VALUE / 2 => {
+                    let c = self.rng.get_usize(1..1000);
+                    break ('-', c, (a + c, b + c));
+                }
+                2 if !is_child_lhs && a.max(b) < MAX_VALUE / 2 => {
+                    let c = a.max(b) + self.rng.get_usize(1..1000);
+                    break ('-', c, (c - a, c - b));
+                }
+                3 if !divisors.is_empty() => {
+                    let c = *self.rng.choose(&divisors);
+                    break ('*', c, (a / c, b / c));
+                }
+                4 if is_child_lhs && a.max(b) < MAX_VALUE / 10 => {
+                    let c = self.rng.get_usize(2..10);
+                    break ('/', c, (a * c, b * c));
+                }
+                _ => {}
+            }
+        };
+        let constant = self.add_constant(constant, depth);
+        if is_child_lhs {
+            self.add_operation(&name, &child, operator, &constant);
+        } else {
+            self.add_operation(&name, &constant, operator, &child);
+        }
+        self.add_humn_path(child, child_values, length - 1, depth);
+    }
+}
+
+fn get_gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        get_gcd(b, a % b)
+    }
+}
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let path_length = size.pick(3, 20, 70);
+    let depth = size.pick(2, 4, 6);
+    let root_values = (rng.get_usize(1000..1000000), rng.get_usize(1000..1000000));
+    let mut monkeys = Monkeys {
+        rng,
+        names: HashSet::new(),
+        lines: vec![],
+    };
+    let constant = monkeys.add_constant(root_values.1, depth);
+    let child = monkeys.get_name();
+    if monkeys.rng.get_bool(0.5) {
+        monkeys.add_operation("root", &child, '+', &constant);
+    } else {
+        monkeys.add_operation("root", &constant, '+', &child);
+    }
+    monkeys.add_humn_path(child, root_values, path_length, depth);
+    let mut lines = monkeys.lines;
+    rng.shuffle(&mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
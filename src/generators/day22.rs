@@ -0,0 +1,77 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// all 11 ways to unfold a cube, any of them can be rotated or flipped
+pub const NETS: [&[&str]; 11] = [
+    &["#...", "####", "#..."],
+    &["#...", "####", ".#.."],
+    &["#...", "####", "..#."],
+    &["#...", "####", "...#"],
+    &[".#..", "####", ".#.."],
+    &[".#..", "####", "..#."],
+    &["##..", ".###", ".#.."],
+    &["##..", ".###", "..#."],
+    &["##..", ".###", "...#"],
+    &["##..", ".##.", "..##"],
+    &["###..", "..###"],
+];
+
+fn rotate(net: Vec<Vec<bool>>) -> Vec<Vec<bool>> {
+    (0..net[0].len())
+        .map(|j| (0..net.len()).rev().map(|i| net[i][j]).collect())
+        .collect()
+}
+
+fn get_net(rng: &mut Rng, net: &[&str]) -> Vec<Vec<bool>> {
+    let mut net = net
+        .iter()
+        .map(|row| row.chars().map(|c| c == '#').collect::<Vec<bool>>())
+        .collect::<Vec<Vec<bool>>>();
+    for _ in 0..rng.get_usize(0..4) {
+        net = rotate(net);
+    }
+    if rng.get_bool(0.5) {
+        net.iter_mut().for_each(|row| row.reverse());
+    }
+    net
+}
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let net = *rng.choose(&NETS);
+    generate_with_net(rng, size, net)
+}
+
+pub fn generate_with_net(rng: &mut Rng, size: Size, net: &[&str]) -> String {
+    let face_size = size.pick(4, 10, 50);
+    let num_of_moves = size.pick(10, 500, 2000);
+    let net = get_net(rng, net);
+    let mut map = String::new();
+    let mut is_start_placed = false;
+    for i in 0..net.len() * face_size {
+        let row = (0..net[0].len() * face_size)
+            .map(|j| {
+                if !net[i / face_size][j / face_size] {
+                    ' '
+                } else if !is_start_placed {
+                    is_start_placed = true;
+                    '.'
+                } else if rng.get_bool(0.1) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+        map += row.trim_end();
+        map.push('\n');
+    }
+    let mut instructions = String::new();
+    for i in 0..num_of_moves {
+        if i > 0 {
+            instructions.push(if rng.get_bool(0.5) { 'R' } else { 'L' });
+        }
+        instructions += &rng.get_usize(1..50).to_string();
+    }
+    format!("{}\n{}\n", map, instructions)
+}
@@ -0,0 +1,16 @@
+use utils::random::Rng;
+
+use super::Size;
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let side = size.pick(7, 30, 75);
+    (0..side)
+        .map(|_| {
+            let mut row = (0..side)
+                .map(|_| if rng.get_bool(0.5) { '#' } else { '.' })
+                .collect::<String>();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,26 @@
+use utils::random::Rng;
+
+use super::Size;
+
+// no blizzard goes up or down in the entrance and exit columns, as it would leave the valley through them
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let (height, width) = size.pick((4, 6), (15, 50), (25, 120));
+    let mut output = format!("#.{}\n", "#".repeat(width));
+    for _ in 0..height {
+        let row = (0..width)
+            .map(|j| {
+                let is_open_column = j == 0 || j == width - 1;
+                match rng.get_usize(0..8) {
+                    0 => '>',
+                    1 => '<',
+                    2 if !is_open_column => '^',
+                    3 if !is_open_column => 'v',
+                    _ => '.',
+                }
+            })
+            .collect::<String>();
+        output += &format!("#{}#\n", row);
+    }
+    output += &format!("{}.#\n", "#".repeat(width));
+    output
+}
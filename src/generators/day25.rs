@@ -0,0 +1,14 @@
+use utils::random::Rng;
+
+use super::Size;
+use crate::days::day25::Solver;
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_numbers = size.pick(10, 100, 1000);
+    (0..num_of_numbers)
+        .map(|_| {
+            let max = 5isize.pow(rng.get_usize(1..20) as u32);
+            format!("{}\n", Solver::encode_snafu(rng.get_isize(1..max)))
+        })
+        .collect()
+}
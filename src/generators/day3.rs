@@ -0,0 +1,56 @@
+use utils::random::Rng;
+
+use super::Size;
+
+fn get_item(i: usize) -> char {
+    if i < 26 {
+        (b'a' + i as u8) as char
+    } else {
+        (b'A' + (i - 26) as u8) as char
+    }
+}
+
+// every group of three shares exactly one badge, and each rucksack shares exactly one item between its compartments,
+// that's guaranteed by giving every rucksack in a group its own pool of items, only the badge is common
+fn generate_group(rng: &mut Rng, compartment_size: usize) -> Vec<String> {
+    let mut items = (0..52).collect::<Vec<usize>>();
+    rng.shuffle(&mut items);
+    let badge = items.pop().unwrap();
+    items
+        .chunks(17)
+        .map(|pool| {
+            let shared_item = pool[0];
+            let (left_pool, right_pool) = pool[1..].split_at(8);
+            let mut left = vec![shared_item];
+            let mut right = vec![shared_item];
+            if rng.get_bool(0.5) {
+                left.push(badge);
+            } else {
+                right.push(badge);
+            }
+            while left.len() < compartment_size {
+                left.push(*rng.choose(left_pool));
+            }
+            while right.len() < compartment_size {
+                right.push(*rng.choose(right_pool));
+            }
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            left.iter()
+                .chain(right.iter())
+                .map(|i| get_item(*i))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_groups = size.pick(2, 100, 10000);
+    (0..num_of_groups)
+        .flat_map(|_| {
+            let compartment_size = rng.get_usize(2..17);
+            generate_group(rng, compartment_size)
+        })
+        .map(|rucksack| rucksack + "\n")
+        .collect()
+}
@@ -0,0 +1,18 @@
+use utils::random::Rng;
+
+use super::Size;
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_pairs = size.pick(10, 1000, 100000);
+    let get_range = |rng: &mut Rng| {
+        let start = rng.get_usize(1..100);
+        (start, rng.get_usize(start..100))
+    };
+    (0..num_of_pairs)
+        .map(|_| {
+            let (a, b) = get_range(rng);
+            let (c, d) = get_range(rng);
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+use utils::random::Rng;
+
+use super::Size;
+
+fn render_stacks(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap();
+    let mut lines = (0..height)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => String::from("   "),
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>();
+    lines.push(
+        (1..=stacks.len())
+            .map(|i| format!(" {} ", i))
+            .collect::<Vec<String>>()
+            .join(" "),
+    );
+    lines.join("\n")
+}
+
+// moves never take the last crate off a stack, so there's always something on top at the end,
+// the solver works out the number of stacks from the moves, so the last one is always a target at least once
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_stacks = size.pick(3, 9, 9);
+    let num_of_moves = size.pick(5, 500, 50000);
+    let mut stacks = (0..num_of_stacks)
+        .map(|_| {
+            (0..rng.get_usize(1..9))
+                .map(|_| (b'A' + rng.get_usize(0..26) as u8) as char)
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+    let drawing = render_stacks(&stacks);
+    let mut moves = vec![];
+    while moves.len() < num_of_moves {
+        let from = rng.get_usize(0..num_of_stacks);
+        let to = if moves.is_empty() {
+            num_of_stacks - 1
+        } else {
+            rng.get_usize(0..num_of_stacks)
+        };
+        if from == to || stacks[from].len() < 2 {
+            continue;
+        }
+        let count = rng.get_usize(1..stacks[from].len());
+        let split_point = stacks[from].len() - count;
+        let moved = stacks[from].split_off(split_point);
+        stacks[to].extend(moved);
+        moves.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+    }
+    format!("{}\n\n{}\n", drawing, moves.join("\n"))
+}
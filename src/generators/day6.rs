@@ -0,0 +1,19 @@
+use utils::random::Rng;
+
+use super::{get_lowercase_letter, Size};
+
+// the noise before the markers only uses three letters, so neither marker can show up there by accident
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let len = size.pick(30, 4096, 1000000);
+    let noise_len = rng.get_usize(len / 4..len / 2);
+    let mut signal = (0..noise_len)
+        .map(|_| *rng.choose(&['a', 'b', 'c']))
+        .collect::<String>();
+    let mut letters = ('a'..='z').collect::<Vec<char>>();
+    rng.shuffle(&mut letters);
+    signal.extend(letters.iter().take(14));
+    while signal.len() < len {
+        signal.push(get_lowercase_letter(rng));
+    }
+    signal + "\n"
+}
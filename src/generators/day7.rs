@@ -0,0 +1,115 @@
+use utils::random::Rng;
+
+use super::{get_lowercase_letter, Size};
+
+struct Directory {
+    name: String,
+    depth: usize,
+    files: Vec<(String, usize)>,
+    children: Vec<usize>,
+}
+
+fn get_name(rng: &mut Rng) -> String {
+    (0..rng.get_usize(1..9))
+        .map(|_| get_lowercase_letter(rng))
+        .collect()
+}
+
+// most files are small, but some are huge, so both parts have something to find
+fn get_file_size(rng: &mut Rng) -> usize {
+    10f64.powf(3.0 + rng.get_usize(0..3500) as f64 / 1000.0) as usize
+}
+
+fn get_total_size(directories: &[Directory], i: usize) -> usize {
+    directories[i]
+        .files
+        .iter()
+        .map(|file| file.1)
+        .sum::<usize>()
+        + directories[i]
+            .children
+            .iter()
+            .map(|child| get_total_size(directories, *child))
+            .sum::<usize>()
+}
+
+// the session walks the whole tree once, listing every directory right after entering it
+fn write_session(rng: &mut Rng, directories: &[Directory], i: usize, lines: &mut Vec<String>) {
+    lines.push(String::from("$ ls"));
+    let mut entries = directories[i]
+        .children
+        .iter()
+        .map(|child| format!("dir {}", directories[*child].name))
+        .chain(
+            directories[i]
+                .files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut entries);
+    lines.extend(entries);
+    directories[i].children.iter().for_each(|child| {
+        lines.push(format!("$ cd {}", directories[*child].name));
+        write_session(rng, directories, *child, lines);
+        lines.push(String::from("$ cd .."));
+    });
+}
+
+// the disk is 70000000 big and the update needs 30000000, file sizes get scaled so the tree takes more than 40000000,
+// so part B always has something to delete
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_directories = size.pick(5, 40, 200);
+    let max_depth = size.pick(3, 6, 10);
+    let mut directories = vec![Directory {
+        name: String::from("/"),
+        depth: 0,
+        files: vec![],
+        children: vec![],
+    }];
+    while directories.len() < num_of_directories {
+        let parent = rng.get_usize(0..directories.len());
+        let name = get_name(rng);
+        let is_name_taken = directories[parent]
+            .children
+            .iter()
+            .any(|child| directories[*child].name == name);
+        if directories[parent].depth < max_depth && !is_name_taken {
+            directories.push(Directory {
+                name,
+                depth: directories[parent].depth + 1,
+                files: vec![],
+                children: vec![],
+            });
+            let child = directories.len() - 1;
+            directories[parent].children.push(child);
+        }
+    }
+    for i in 0..directories.len() {
+        for _ in 0..rng.get_usize(1..6) {
+            let extension = *rng.choose(&["", ".txt", ".dat", ".log"]);
+            let name = get_name(rng) + extension;
+            let directory = &directories[i];
+            let is_name_taken = directory.files.iter().any(|file| file.0 == name)
+                || directory
+                    .children
+                    .iter()
+                    .any(|child| directories[*child].name == name);
+            if !is_name_taken {
+                let file_size = get_file_size(rng);
+                directories[i].files.push((name, file_size));
+            }
+        }
+    }
+    let total_size = get_total_size(&directories, 0);
+    let target_size = rng.get_usize(41000000..69000000);
+    directories.iter_mut().for_each(|directory| {
+        directory
+            .files
+            .iter_mut()
+            .for_each(|file| file.1 = (file.1 * target_size / total_size).max(1));
+    });
+    let mut lines = vec![String::from("$ cd /")];
+    write_session(rng, &directories, 0, &mut lines);
+    lines.join("\n") + "\n"
+}
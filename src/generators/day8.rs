@@ -0,0 +1,15 @@
+use utils::random::Rng;
+
+use super::Size;
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let side = size.pick(5, 99, 1000);
+    (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| char::from_digit(rng.get_usize(0..10) as u32, 10).unwrap())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
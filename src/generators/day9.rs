@@ -0,0 +1,16 @@
+use utils::random::Rng;
+
+use super::Size;
+
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let num_of_moves = size.pick(10, 2000, 200000);
+    (0..num_of_moves)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['R', 'U', 'L', 'D']),
+                rng.get_usize(1..20)
+            )
+        })
+        .collect()
+}
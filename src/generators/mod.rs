@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use utils::random::Rng;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+// large is about the size of a real input, or bigger where the solvers can take it,
+// days with a fixed input shape (like day10's 240 cycles) ignore it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
+impl Size {
+    pub fn pick<T>(&self, small: T, medium: T, large: T) -> T {
+        match self {
+            Size::Small => small,
+            Size::Medium => medium,
+            Size::Large => large,
+        }
+    }
+}
+
+impl FromStr for Size {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "small" => Ok(Size::Small),
+            "medium" => Ok(Size::Medium),
+            "large" => Ok(Size::Large),
+            _ => Err(format!(
+                "Unknown size \"{}\", use small, medium or large",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pick("small", "medium", "large"))
+    }
+}
+
+pub fn generate(day: u32, size: Size, seed: u64) -> String {
+    let rng = &mut Rng::new(seed);
+    match day {
+        1 => day1::generate(rng, size),
+        2 => day2::generate(rng, size),
+        3 => day3::generate(rng, size),
+        4 => day4::generate(rng, size),
+        5 => day5::generate(rng, size),
+        6 => day6::generate(rng, size),
+        7 => day7::generate(rng, size),
+        8 => day8::generate(rng, size),
+        9 => day9::generate(rng, size),
+        10 => day10::generate(rng, size),
+        11 => day11::generate(rng, size),
+        12 => day12::generate(rng, size),
+        13 => day13::generate(rng, size),
+        14 => day14::generate(rng, size),
+        15 => day15::generate(rng, size),
+        16 => day16::generate(rng, size),
+        17 => day17::generate(rng, size),
+        18 => day18::generate(rng, size),
+        19 => day19::generate(rng, size),
+        20 => day20::generate(rng, size),
+        21 => day21::generate(rng, size),
+        22 => day22::generate(rng, size),
+        23 => day23::generate(rng, size),
+        24 => day24::generate(rng, size),
+        25 => day25::generate(rng, size),
+        _ => panic!("There's no generator for day {}", day),
+    }
+}
+
+pub fn get_lowercase_letter(rng: &mut Rng) -> char {
+    (b'a' + rng.get_usize(0..26) as u8) as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_solver;
+    use utils::input::Input;
+
+    #[test]
+    fn determinism_test() {
+        (1..=25).for_each(|day| {
+            assert_eq!(generate(day, Size::Small, 7), generate(day, Size::Small, 7));
+            assert_ne!(generate(day, Size::Small, 7), generate(day, Size::Small, 8));
+        });
+    }
+
+    // day15 always works on the full 4000000 square and day19 takes too long without optimisations
+    #[test]
+    fn solvability_test() {
+        (1..=25)
+            .filter(|day| ![15, 19].contains(day))
+            .for_each(|day| {
+                (0..3).for_each(|seed| {
+                    let input = generate(day, Size::Small, seed);
+                    let solver =
+                        create_solver(&format!("day{}", day), |_| Input::from(input.clone()));
+                    solver.get_part_a_result();
+                    solver.get_part_b_result();
+                });
+            });
+        // a few seeds can easily miss some of the cube nets, so day22 goes through all of them
+        day22::NETS.iter().for_each(|net| {
+            (0..8).for_each(|seed| {
+                let input = day22::generate_with_net(&mut Rng::new(seed), Size::Small, net);
+                let solver = create_solver("day22", |_| Input::from(input.clone()));
+                solver.get_part_a_result();
+                solver.get_part_b_result();
+            });
+        });
+    }
+}
@@ -1,47 +1,92 @@
 extern crate utils;
 
-use std::env;
-use std::time::SystemTime;
+use std::fs;
+use std::io::BufRead;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use utils::config::Config;
+use utils::input::Input;
 use utils::network::*;
 use utils::ChallengeSolver;
 
+mod args;
 mod days;
+mod generators;
+#[cfg(feature = "reference")]
+mod reference;
+use args::{Args, Command};
 use days::*;
+use generators::Size;
 
-fn get_solver(day: &str) -> Box<dyn ChallengeSolver> {
+// input that's already in memory goes straight to the solver, anything else gets streamed
+fn construct<S>(input: Input, new: fn(String) -> S, from_reader: fn(Box<dyn BufRead>) -> S) -> S {
+    match input {
+        Input::Text(text) => new(text),
+        input => from_reader(input.into_reader()),
+    }
+}
+
+// inputs passed with --input are memory mapped, days with a from_reader constructor never copy them whole
+fn get_solver(
+    day: &str,
+    config: &Config,
+    input_path: Option<&Path>,
+    reference: bool,
+) -> Box<dyn ChallengeSolver> {
+    let input = |day| match input_path {
+        Some(path) => Input::open(path)
+            .unwrap_or_else(|err| panic!("Can't open \"{}\": {}", path.display(), err)),
+        None => Input::from(get_input_for_day(config, config.year, day)),
+    };
+    if reference {
+        return create_reference_solver(day, input);
+    }
+    create_solver(day, input)
+}
+
+#[cfg(feature = "reference")]
+fn create_reference_solver<F: Fn(u32) -> Input>(day: &str, input: F) -> Box<dyn ChallengeSolver> {
+    reference::create_solver(day, input)
+}
+
+#[cfg(not(feature = "reference"))]
+fn create_reference_solver<F: Fn(u32) -> Input>(_day: &str, _input: F) -> Box<dyn ChallengeSolver> {
+    panic!("Reference solvers need the \"reference\" feature");
+}
+
+fn create_solver<F: Fn(u32) -> Input>(day: &str, input: F) -> Box<dyn ChallengeSolver> {
     match day {
-        "day1" => Box::new(day1::Solver::new(get_input_for_day(2022, 1))),
-        "day2" => Box::new(day2::Solver::new(get_input_for_day(2022, 2))),
-        "day3" => Box::new(day3::Solver::new(get_input_for_day(2022, 3))),
-        "day4" => Box::new(day4::Solver::new(get_input_for_day(2022, 4))),
-        "day5" => Box::new(day5::Solver::new(get_input_for_day(2022, 5))),
-        "day6" => Box::new(day6::Solver::new(get_input_for_day(2022, 6))),
-        "day7" => Box::new(day7::Solver::new(get_input_for_day(2022, 7))),
-        "day8" => Box::new(day8::Solver::new(get_input_for_day(2022, 8))),
-        "day9" => Box::new(day9::Solver::new(get_input_for_day(2022, 9))),
-        "day10" => Box::new(day10::Solver::new(get_input_for_day(2022, 10))),
-        "day11" => Box::new(day11::Solver::new(get_input_for_day(2022, 11))),
-        "day12" => Box::new(day12::Solver::new(get_input_for_day(2022, 12))),
-        "day13" => Box::new(day13::Solver::new(get_input_for_day(2022, 13))),
-        "day14" => Box::new(day14::Solver::new(get_input_for_day(2022, 14))),
-        "day15" => Box::new(day15::Solver::new(get_input_for_day(2022, 15))),
-        "day16" => Box::new(day16::Solver::new(get_input_for_day(2022, 16))),
-        "day17" => Box::new(day17::Solver::new(get_input_for_day(2022, 17))),
-        "day18" => Box::new(day18::Solver::new(get_input_for_day(2022, 18))),
-        "day19" => Box::new(day19::Solver::new(get_input_for_day(2022, 19))),
-        "day20" => Box::new(day20::Solver::new(get_input_for_day(2022, 20))),
-        "day21" => Box::new(day21::Solver::new(get_input_for_day(2022, 21))),
-        "day22" => Box::new(day22::Solver::new(get_input_for_day(2022, 22))),
-        "day23" => Box::new(day23::Solver::new(get_input_for_day(2022, 23))),
-        "day24" => Box::new(day24::Solver::new(get_input_for_day(2022, 24))),
-        "day25" => Box::new(day25::Solver::new(get_input_for_day(2022, 25))),
+        "day1" => Box::new(day1::Solver::new(input(1).into_string())),
+        "day2" => Box::new(day2::Solver::new(input(2).into_string())),
+        "day3" => Box::new(day3::Solver::new(input(3).into_string())),
+        "day4" => Box::new(day4::Solver::new(input(4).into_string())),
+        "day5" => Box::new(day5::Solver::new(input(5).into_string())),
+        "day6" => Box::new(day6::Solver::new(input(6).into_string())),
+        "day7" => Box::new(day7::Solver::new(input(7).into_string())),
+        "day8" => Box::new(day8::Solver::new(input(8).into_string())),
+        "day9" => Box::new(day9::Solver::new(input(9).into_string())),
+        "day10" => Box::new(day10::Solver::new(input(10).into_string())),
+        "day11" => Box::new(day11::Solver::new(input(11).into_string())),
+        "day12" => Box::new(day12::Solver::new(input(12).into_string())),
+        "day13" => Box::new(day13::Solver::new(input(13).into_string())),
+        "day14" => Box::new(day14::Solver::new(input(14).into_string())),
+        "day15" => Box::new(construct(input(15), day15::Solver::new, day15::Solver::from_reader)),
+        "day16" => Box::new(day16::Solver::new(input(16).into_string())),
+        "day17" => Box::new(day17::Solver::new(input(17).into_string())),
+        "day18" => Box::new(construct(input(18), day18::Solver::new, day18::Solver::from_reader)),
+        "day19" => Box::new(construct(input(19), day19::Solver::new, day19::Solver::from_reader)),
+        "day20" => Box::new(construct(input(20), day20::Solver::new, day20::Solver::from_reader)),
+        "day21" => Box::new(day21::Solver::new(input(21).into_string())),
+        "day22" => Box::new(day22::Solver::new(input(22).into_string())),
+        "day23" => Box::new(day23::Solver::new(input(23).into_string())),
+        "day24" => Box::new(day24::Solver::new(input(24).into_string())),
+        "day25" => Box::new(day25::Solver::new(input(25).into_string())),
         _ => panic!("Unknown or missing argument")
     }
 }
 
-fn main() {
-    let env_arg = env::args().nth(1).unwrap_or_default();
-    let solver = get_solver(env_arg.as_ref());
+fn run_solver(day: &str, config: &Config, input_path: Option<&Path>, reference: bool) {
+    let solver = get_solver(day, config, input_path, reference);
 
     println!("-----------PART A SOLUTION-----------");
     let timer = SystemTime::now();
@@ -62,3 +107,73 @@ fn main() {
         duration.subsec_nanos()
     );
 }
+
+fn show_leaderboard(board_id: Option<u64>, config: &Config) {
+    let board_id = board_id
+        .or(config.leaderboard)
+        .expect("Please pass leaderboard ID or set \"leaderboard\" in the config file");
+    let leaderboard = get_leaderboard(config, config.year, board_id);
+
+    println!("-------------STANDINGS---------------");
+    print!("{}", leaderboard.render_standings());
+    println!("-----------PART 2 DELTAS-------------");
+    print!("{}", leaderboard.render_delta_times());
+    println!("------------STAR HISTORY-------------");
+    leaderboard.get_standings().iter().for_each(|member| {
+        print!("{}", leaderboard.render_star_history(member));
+    });
+}
+
+fn fetch(days: &[u32], config: &Config) {
+    let report = fetch_inputs(config, config.year, days);
+    print!("{}", report.render());
+}
+
+fn generate(day: u32, size: Option<&str>, seed: Option<u64>, output: Option<&Path>, config: &Config) {
+    let size = size
+        .map(|size| size.parse::<Size>().unwrap_or_else(|e| panic!("{}", e)))
+        .unwrap_or(Size::Large);
+    // without a seed every run gives something new, the one used gets printed so it can be repeated
+    let seed = seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64
+    });
+    let input = generators::generate(day, size, seed);
+    let path = output
+        .map(|path| path.to_path_buf())
+        .unwrap_or_else(|| config.get_generated_input_path(day, &size.to_string(), seed));
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).expect("Can't create directory for generated input");
+    }
+    fs::write(&path, input).expect("Can't write generated input");
+    println!("Generated day {} input ({}, seed {}): {}", day, size, seed, path.display());
+}
+
+fn main() {
+    let args = Args::parse();
+    let mut config = Config::load();
+    if args.session_file.is_some() {
+        config.session_file = args.session_file;
+    }
+    if let Some(year) = args.year {
+        config.year = year;
+    }
+    config.offline |= args.offline;
+    config.normalize |= args.normalize;
+    config.raw = args.raw;
+
+    match args.command {
+        Command::Solve(day) => run_solver(&day, &config, args.input.as_deref(), args.reference),
+        Command::Leaderboard(board_id) => show_leaderboard(board_id, &config),
+        Command::Fetch(days) => fetch(&days, &config),
+        Command::Generate(day) => generate(
+            day,
+            args.size.as_deref(),
+            args.seed,
+            args.output.as_deref(),
+            &config,
+        ),
+    }
+}
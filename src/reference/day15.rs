@@ -0,0 +1,90 @@
+use utils::{
+    parse::{self, Pattern},
+    ChallengeSolver,
+};
+
+// goes through the positions one by one, the only trick is skipping to the end of the range of the sensor
+// that covers the current one
+pub struct Solver {
+    input: Vec<((isize, isize), (isize, isize))>,
+    row: isize,
+    area_size: isize,
+}
+
+fn get_distance(a: (isize, isize), b: (isize, isize)) -> isize {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+impl Solver {
+    pub fn new(input: String) -> Solver {
+        let pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+        let input = parse::parse_lines(&input, |line| {
+            let numbers = pattern.extract::<isize>(line)?;
+            Ok(((numbers[0], numbers[1]), (numbers[2], numbers[3])))
+        })
+        .unwrap();
+        Solver {
+            input,
+            row: 2000000,
+            area_size: 4000000,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_area(mut self, row: isize, area_size: isize) -> Solver {
+        self.row = row;
+        self.area_size = area_size;
+        self
+    }
+
+    // the first x to the right of pos that the sensor covering pos doesn't reach
+    fn get_covered_until(&self, pos: (isize, isize)) -> Option<isize> {
+        self.input.iter().find_map(|(sensor, beacon)| {
+            let reach = get_distance(*sensor, *beacon) - (sensor.1 - pos.1).abs();
+            if get_distance(*sensor, pos) <= get_distance(*sensor, *beacon) {
+                return Some(sensor.0 + reach + 1);
+            }
+            None
+        })
+    }
+}
+
+impl ChallengeSolver for Solver {
+    fn get_part_a_result(&self) -> String {
+        let min_x = self
+            .input
+            .iter()
+            .map(|(sensor, beacon)| sensor.0 - get_distance(*sensor, *beacon))
+            .min()
+            .unwrap();
+        let max_x = self
+            .input
+            .iter()
+            .map(|(sensor, beacon)| sensor.0 + get_distance(*sensor, *beacon))
+            .max()
+            .unwrap();
+        let result = (min_x..=max_x)
+            .filter(|x| {
+                let pos = (*x, self.row);
+                self.get_covered_until(pos).is_some()
+                    && self
+                        .input
+                        .iter()
+                        .all(|(sensor, beacon)| *sensor != pos && *beacon != pos)
+            })
+            .count();
+        result.to_string()
+    }
+    fn get_part_b_result(&self) -> String {
+        for y in 0..=self.area_size {
+            let mut x = 0;
+            while x <= self.area_size {
+                match self.get_covered_until((x, y)) {
+                    Some(next_x) => x = next_x,
+                    None => return (x * 4000000 + y).to_string(),
+                }
+            }
+        }
+        panic!("There's no hole in the area");
+    }
+}
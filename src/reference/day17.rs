@@ -0,0 +1,71 @@
+use utils::ChallengeSolver;
+
+// (y, x) of every part of the rocks, y grows upwards
+const ROCKS: [&[(usize, usize)]; 5] = [
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+    &[(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(0, 0), (0, 1), (1, 0), (1, 1)],
+];
+
+// drops every single rock into a chamber that remembers everything
+pub struct Solver {
+    input: Vec<isize>,
+}
+
+impl Solver {
+    pub fn new(input: String) -> Solver {
+        let input = input
+            .trim()
+            .chars()
+            .map(|c| if c == '<' { -1 } else { 1 })
+            .collect();
+        Solver { input }
+    }
+
+    fn fits(chamber: &[[bool; 7]], rock: &[(usize, usize)], y: usize, x: isize) -> bool {
+        rock.iter().all(|(dy, dx)| {
+            let x = x + *dx as isize;
+            (0..7).contains(&x) && chamber.get(y + dy).is_none_or(|row| !row[x as usize])
+        })
+    }
+
+    pub fn get_height(&self, num_of_rocks: usize) -> usize {
+        let mut chamber: Vec<[bool; 7]> = vec![];
+        let mut wind = self.input.iter().cycle();
+        for i in 0..num_of_rocks {
+            let rock = ROCKS[i % 5];
+            let (mut y, mut x) = (chamber.len() + 3, 2);
+            loop {
+                let pushed_x = x + wind.next().unwrap();
+                if Solver::fits(&chamber, rock, y, pushed_x) {
+                    x = pushed_x;
+                }
+                if y == 0 || !Solver::fits(&chamber, rock, y - 1, x) {
+                    break;
+                }
+                y -= 1;
+            }
+            rock.iter().for_each(|(dy, dx)| {
+                while chamber.len() <= y + dy {
+                    chamber.push([false; 7]);
+                }
+                chamber[y + dy][x as usize + dx] = true;
+            });
+        }
+        chamber.len()
+    }
+}
+
+impl ChallengeSolver for Solver {
+    fn get_part_a_result(&self) -> String {
+        let result = self.get_height(2022);
+        result.to_string()
+    }
+    // really obvious, but it won't finish in any reasonable time
+    fn get_part_b_result(&self) -> String {
+        let result = self.get_height(1000000000000);
+        result.to_string()
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use utils::ChallengeSolver;
+
+// [ore, clay, obsidian, geode] for both the resources and the bots
+type Resources = [usize; 4];
+
+// costs of the ore, clay, obsidian and geode bots
+type Blueprint = [Resources; 4];
+
+// tries every possible choice each minute, only merging the ones that end up in the same state
+pub struct Solver {
+    input: Vec<Blueprint>,
+}
+
+impl Solver {
+    pub fn new(input: String) -> Solver {
+        let input = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let nums = line
+                    .split_whitespace()
+                    .filter_map(|word| word.parse::<usize>().ok())
+                    .collect::<Vec<usize>>();
+                [
+                    [nums[0], 0, 0, 0],
+                    [nums[1], 0, 0, 0],
+                    [nums[2], nums[3], 0, 0],
+                    [nums[4], 0, nums[5], 0],
+                ]
+            })
+            .collect();
+        Solver { input }
+    }
+
+    fn get_max_geodes_for_blueprint(blueprint: &Blueprint, time_limit: usize) -> usize {
+        let mut states: HashSet<(Resources, Resources)> = HashSet::from([([0; 4], [1, 0, 0, 0])]);
+        for _ in 0..time_limit {
+            let mut next_states = HashSet::new();
+            for (resources, bots) in states {
+                let mut collected = resources;
+                (0..4).for_each(|i| collected[i] += bots[i]);
+                next_states.insert((collected, bots));
+                for (bot, cost) in blueprint.iter().enumerate() {
+                    if (0..4).all(|i| resources[i] >= cost[i]) {
+                        let mut next_resources = collected;
+                        let mut next_bots = bots;
+                        (0..4).for_each(|i| next_resources[i] -= cost[i]);
+                        next_bots[bot] += 1;
+                        next_states.insert((next_resources, next_bots));
+                    }
+                }
+            }
+            states = next_states;
+        }
+        states
+            .iter()
+            .map(|(resources, _)| resources[3])
+            .max()
+            .unwrap()
+    }
+
+    pub fn get_max_geodes(&self, time_limit: usize, num_of_blueprints: usize) -> Vec<usize> {
+        self.input
+            .iter()
+            .take(num_of_blueprints)
+            .map(|blueprint| Solver::get_max_geodes_for_blueprint(blueprint, time_limit))
+            .collect()
+    }
+}
+
+impl ChallengeSolver for Solver {
+    fn get_part_a_result(&self) -> String {
+        let result = self
+            .get_max_geodes(24, self.input.len())
+            .iter()
+            .enumerate()
+            .map(|(i, geodes)| geodes * (i + 1))
+            .sum::<usize>();
+        result.to_string()
+    }
+    fn get_part_b_result(&self) -> String {
+        let result = self.get_max_geodes(32, 3).iter().product::<usize>();
+        result.to_string()
+    }
+}
@@ -0,0 +1,51 @@
+use utils::ChallengeSolver;
+
+// moves the numbers around in a plain vector, looking each of them up by its original position
+pub struct Solver {
+    input: Vec<isize>,
+}
+
+impl Solver {
+    pub fn new(input: String) -> Solver {
+        let input = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.trim().parse().unwrap())
+            .collect();
+        Solver { input }
+    }
+
+    fn get_grove_coordinates(&self, key: isize, rounds: usize) -> isize {
+        let len = self.input.len();
+        let mut list = self
+            .input
+            .iter()
+            .map(|n| n * key)
+            .enumerate()
+            .collect::<Vec<(usize, isize)>>();
+        for _ in 0..rounds {
+            for i in 0..len {
+                let pos = list.iter().position(|item| item.0 == i).unwrap();
+                let item = list.remove(pos);
+                let new_pos = (pos as isize + item.1).rem_euclid(len as isize - 1) as usize;
+                list.insert(new_pos, item);
+            }
+        }
+        let zero_pos = list.iter().position(|item| item.1 == 0).unwrap();
+        [1000, 2000, 3000]
+            .iter()
+            .map(|offset| list[(zero_pos + offset) % len].1)
+            .sum()
+    }
+}
+
+impl ChallengeSolver for Solver {
+    fn get_part_a_result(&self) -> String {
+        let result = self.get_grove_coordinates(1, 1);
+        result.to_string()
+    }
+    fn get_part_b_result(&self) -> String {
+        let result = self.get_grove_coordinates(811589153, 10);
+        result.to_string()
+    }
+}
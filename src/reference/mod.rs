@@ -0,0 +1,160 @@
+pub mod day15;
+pub mod day17;
+pub mod day19;
+pub mod day20;
+
+use utils::{input::Input, ChallengeSolver};
+
+// slow but obvious solutions for the days that rely on shortcuts, to check the real ones against
+pub fn create_solver<F: Fn(u32) -> Input>(day: &str, input: F) -> Box<dyn ChallengeSolver> {
+    match day {
+        "day15" => Box::new(day15::Solver::new(input(15).into_string())),
+        "day17" => Box::new(day17::Solver::new(input(17).into_string())),
+        "day19" => Box::new(day19::Solver::new(input(19).into_string())),
+        "day20" => Box::new(day20::Solver::new(input(20).into_string())),
+        _ => panic!("There's no reference solver for {}", day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::generators::{self, Size};
+    use utils::random::Rng;
+    use utils::ChallengeSolver;
+
+    fn get_size(seed: u64) -> Size {
+        if seed.is_multiple_of(2) {
+            Size::Small
+        } else {
+            Size::Medium
+        }
+    }
+
+    #[test]
+    fn day15_test() {
+        for seed in 0..100 {
+            let mut rng = Rng::new(seed);
+            let area_size = rng.get_isize(10..60);
+            let hole = (
+                rng.get_isize(0..area_size + 1),
+                rng.get_isize(0..area_size + 1),
+            );
+            let row = rng.get_isize(0..area_size + 1);
+            let input =
+                generators::day15::generate_around(&mut rng, get_size(seed), hole, area_size);
+            let solver = days::day15::Solver::new(input.clone()).with_area(row, area_size);
+            let reference = super::day15::Solver::new(input.clone()).with_area(row, area_size);
+
+            assert_eq!(
+                solver.get_part_a_result(),
+                reference.get_part_a_result(),
+                "{}",
+                input
+            );
+            assert_eq!(
+                solver.get_part_b_result(),
+                reference.get_part_b_result(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn day17_test() {
+        // short winds pile the rocks up in odd shapes a lot sooner, so there's many more of them
+        for seed in 0..210 {
+            let mut rng = Rng::new(seed);
+            let (input, num_of_rocks) = if seed < 10 {
+                let input = generators::generate(17, get_size(seed), seed);
+                (input, rng.get_usize(0..2500))
+            } else {
+                let input = (0..rng.get_usize(1..30))
+                    .map(|_| if rng.get_bool(0.5) { '<' } else { '>' })
+                    .collect();
+                (input, rng.get_usize(0..500))
+            };
+            let solver = days::day17::Solver::new(input.clone());
+            let reference = super::day17::Solver::new(input.clone());
+
+            assert_eq!(
+                solver.run_simulation(num_of_rocks),
+                reference.get_height(num_of_rocks),
+                "{} rocks, {}",
+                num_of_rocks,
+                input
+            );
+        }
+    }
+
+    // with the real costs there are hardly any geodes in the time the reference can handle,
+    // so the blueprints are a lot cheaper here
+    fn get_cheap_blueprint(rng: &mut Rng, id: usize) -> String {
+        format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+            Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+            id,
+            rng.get_usize(1..5),
+            rng.get_usize(1..5),
+            rng.get_usize(1..5),
+            rng.get_usize(1..8),
+            rng.get_usize(1..5),
+            rng.get_usize(1..8),
+        )
+    }
+
+    #[test]
+    fn day19_test() {
+        for seed in 0..30 {
+            let mut rng = Rng::new(seed);
+            let input = (1..=2)
+                .map(|id| get_cheap_blueprint(&mut rng, id))
+                .collect::<String>();
+            let time_limit = rng.get_usize(8..15);
+            let solver = days::day19::Solver::new(input.clone());
+            let reference = super::day19::Solver::new(input.clone());
+
+            assert_eq!(
+                solver.get_max_geodes(time_limit, 2),
+                reference.get_max_geodes(time_limit, 2),
+                "{} minutes, {}",
+                time_limit,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn day20_test() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            // besides the generated lists, really short ones where the numbers wrap around many times
+            let input = if seed % 2 == 0 {
+                generators::generate(20, get_size(seed / 2), seed)
+            } else {
+                let mut numbers = (0..rng.get_usize(1..10))
+                    .map(|_| rng.get_isize(1..30) * if rng.get_bool(0.5) { 1 } else { -1 })
+                    .collect::<Vec<isize>>();
+                numbers.push(0);
+                rng.shuffle(&mut numbers);
+                numbers.iter().map(|n| format!("{}\n", n)).collect()
+            };
+            let solver = days::day20::Solver::new(input.clone());
+            let reference = super::day20::Solver::new(input.clone());
+
+            assert_eq!(
+                solver.get_part_a_result(),
+                reference.get_part_a_result(),
+                "{}",
+                input
+            );
+            assert_eq!(
+                solver.get_part_b_result(),
+                reference.get_part_b_result(),
+                "{}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    session_file: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+    year: Option<u32>,
+    offline: Option<bool>,
+    leaderboard: Option<u64>,
+    normalize: Option<bool>,
+}
+
+#[derive(Debug)]
+pub struct Config {
+    pub session: Option<String>,
+    pub session_file: Option<PathBuf>,
+    pub input_dir: PathBuf,
+    pub year: u32,
+    pub offline: bool,
+    pub leaderboard: Option<u64>,
+    pub normalize: bool,
+    pub raw: bool,
+    config_path: Option<PathBuf>,
+}
+
+fn get_home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+fn get_xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| get_home_dir().map(|home| home.join(fallback)))
+}
+
+fn resolve_path(path: &Path, relative_to: &Path) -> PathBuf {
+    if let Ok(stripped) = path.strip_prefix("~") {
+        if let Some(home) = get_home_dir() {
+            return home.join(stripped);
+        }
+    }
+    relative_to.join(path)
+}
+
+#[cfg(unix)]
+pub fn warn_if_world_readable(path: &Path) {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Ok(metadata) = fs::metadata(path) {
+        if metadata.permissions().mode() & 0o004 != 0 {
+            eprintln!(
+                "Warning: \"{}\" holds your session cookie and is world-readable, consider running `chmod 600` on it",
+                path.display()
+            );
+        }
+    }
+}
+
+#[cfg(not(unix))]
+pub fn warn_if_world_readable(_path: &Path) {}
+
+impl Config {
+    pub fn get_config_path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os("AOC_CONFIG").filter(|path| !path.is_empty()) {
+            return Some(PathBuf::from(path));
+        }
+        get_xdg_dir("XDG_CONFIG_HOME", ".config").map(|dir| dir.join("aoc22").join("config.toml"))
+    }
+
+    pub fn get_default_input_dir() -> PathBuf {
+        get_xdg_dir("XDG_CACHE_HOME", ".cache")
+            .map(|dir| dir.join("aoc22"))
+            .unwrap_or_else(|| env::current_dir().unwrap().join("input"))
+    }
+
+    pub fn load() -> Config {
+        let config_path = Config::get_config_path().filter(|path| path.is_file());
+        let config_file = match &config_path {
+            Some(path) => {
+                let content = fs::read_to_string(path).expect("Can't read config file");
+                Config::parse(&content).unwrap_or_else(|err| {
+                    panic!("Config file \"{}\" is invalid: {}", path.display(), err)
+                })
+            }
+            None => ConfigFile::default(),
+        };
+        let config_dir = config_path
+            .as_ref()
+            .and_then(|path| path.parent())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        Config {
+            session: config_file.session,
+            session_file: config_file
+                .session_file
+                .map(|path| resolve_path(&path, &config_dir)),
+            input_dir: config_file
+                .input_dir
+                .map(|path| resolve_path(&path, &config_dir))
+                .unwrap_or_else(Config::get_default_input_dir),
+            year: config_file.year.unwrap_or(2022),
+            offline: Config::is_offline_env_set() || config_file.offline.unwrap_or(false),
+            leaderboard: config_file.leaderboard,
+            normalize: config_file.normalize.unwrap_or(false),
+            raw: false,
+            config_path,
+        }
+    }
+
+    fn is_offline_env_set() -> bool {
+        env::var("AOC_OFFLINE")
+            .map(|value| !matches!(value.trim(), "" | "0" | "false" | "no"))
+            .unwrap_or(false)
+    }
+
+    fn parse(content: &str) -> Result<ConfigFile, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    pub fn get_input_path(&self, year: u32, day: u32) -> PathBuf {
+        self.input_dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    // where inputs were cached before there was a config file, always for 2022
+    pub fn get_legacy_input_path(day: u32) -> PathBuf {
+        PathBuf::from("input").join(format!("day{}.txt", day))
+    }
+
+    pub fn get_generated_input_path(&self, day: u32, size: &str, seed: u64) -> PathBuf {
+        self.input_dir
+            .join("generated")
+            .join(format!("day{}-{}-{}.txt", day, size, seed))
+    }
+
+    pub fn get_leaderboard_path(&self, year: u32, board_id: u64) -> PathBuf {
+        self.input_dir
+            .join(year.to_string())
+            .join(format!("leaderboard-{}.json", board_id))
+    }
+
+    pub fn get_session(&self) -> String {
+        if let Some(session_file) = &self.session_file {
+            warn_if_world_readable(session_file);
+            let session = fs::read_to_string(session_file).unwrap_or_else(|_| {
+                panic!("Can't read session file \"{}\"", session_file.display())
+            });
+            let session = session.trim();
+            return String::from(session.strip_prefix("session=").unwrap_or(session));
+        }
+        if let Ok(session) = env::var("AOC_SESSION") {
+            return session;
+        }
+        if let Some(session) = &self.session {
+            if let Some(config_path) = &self.config_path {
+                warn_if_world_readable(config_path);
+            }
+            return session.clone();
+        }
+        panic!("Please put your session cookie in AOC_SESSION environmental variable, pass it with --session-file or set \"session\" in the config file")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_test() {
+        let config = Config::parse(
+            "session = \"53616c746564\"
+            input_dir = \"~/aoc\"
+            year = 2021
+            offline = true",
+        )
+        .unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("53616c746564"));
+        assert_eq!(config.input_dir, Some(PathBuf::from("~/aoc")));
+        assert_eq!(config.session_file, None);
+        assert_eq!(config.year, Some(2021));
+        assert_eq!(config.offline, Some(true));
+    }
+
+    #[test]
+    fn unknown_keys_test() {
+        assert!(Config::parse("sesion = \"typo\"").is_err());
+    }
+
+    #[test]
+    fn relative_path_test() {
+        let resolved = resolve_path(Path::new("input"), Path::new("/etc/aoc22"));
+
+        assert_eq!(resolved, PathBuf::from("/etc/aoc22/input"));
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// the sequence runs through prefix unique states first, after that it repeats every period steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    prefix: usize,
+    period: usize,
+}
+
+impl Cycle {
+    pub fn new(prefix: usize, period: usize) -> Cycle {
+        if period == 0 {
+            panic!("Cycle period has to be positive");
+        }
+        Cycle { prefix, period }
+    }
+
+    pub fn get_prefix(&self) -> usize {
+        self.prefix
+    }
+
+    pub fn get_period(&self) -> usize {
+        self.period
+    }
+
+    // the earliest step with the same state as step n
+    pub fn get_equivalent_step(&self, n: usize) -> usize {
+        if n < self.prefix {
+            return n;
+        }
+        self.prefix + (n - self.prefix) % self.period
+    }
+
+    // value accumulated after n steps, knowing only the values up to the end of the first repetition,
+    // the increments have to depend on the state alone, so every full period adds the same amount
+    pub fn extrapolate<F: FnMut(usize) -> usize>(&self, n: usize, mut get_value: F) -> usize {
+        if n < self.prefix + self.period {
+            return get_value(n);
+        }
+        let num_of_periods = (n - self.prefix) / self.period;
+        let gain_per_period = get_value(self.prefix + self.period) - get_value(self.prefix);
+        get_value(self.get_equivalent_step(n)) + num_of_periods * gain_per_period
+    }
+}
+
+// all of the functions below only return if the sequence ever repeats
+
+pub fn floyd<S: Clone + PartialEq, F: FnMut(&S) -> S>(start: S, mut step: F) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle::new(prefix, period)
+}
+
+// same result as floyd, but usually with fewer steps
+pub fn brent<S: Clone + PartialEq, F: FnMut(&S) -> S>(start: S, mut step: F) -> Cycle {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+    let mut prefix = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Cycle::new(prefix, period)
+}
+
+// remembers a fingerprint of every step, for simulations that are easier to drive by hand
+// or where only a part of the state decides what happens next
+#[derive(Debug, Clone)]
+pub struct CycleDetector<K> {
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash> CycleDetector<K> {
+    pub fn new() -> CycleDetector<K> {
+        CycleDetector {
+            seen: HashMap::new(),
+        }
+    }
+
+    pub fn get_steps(&self) -> usize {
+        self.seen.len()
+    }
+
+    // fingerprints have to be pushed for consecutive steps, starting with step 0
+    pub fn push(&mut self, key: K) -> Option<Cycle> {
+        let step = self.seen.len();
+        match self.seen.get(&key) {
+            Some(prefix) => Some(Cycle::new(*prefix, step - prefix)),
+            None => {
+                self.seen.insert(key, step);
+                None
+            }
+        }
+    }
+}
+
+impl<K: Eq + Hash> Default for CycleDetector<K> {
+    fn default() -> Self {
+        CycleDetector::new()
+    }
+}
+
+pub fn find_by_key<S, K, F, FK>(start: S, mut step: F, mut get_key: FK) -> Cycle
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    FK: FnMut(&S) -> K,
+{
+    let mut detector = CycleDetector::new();
+    let mut state = start;
+    loop {
+        if let Some(cycle) = detector.push(get_key(&state)) {
+            return cycle;
+        }
+        state = step(&state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next_value(x: &usize) -> usize {
+        (x * x + 1) % 255
+    }
+
+    fn get_sequence(start: usize, len: usize) -> Vec<usize> {
+        std::iter::successors(Some(start), |x| Some(next_value(x)))
+            .take(len)
+            .collect()
+    }
+
+    #[test]
+    fn find_test() {
+        for start in 0..255 {
+            let sequence = get_sequence(start, 600);
+            let cycle = floyd(start, next_value);
+            let (prefix, period) = (cycle.get_prefix(), cycle.get_period());
+
+            assert_eq!(sequence[prefix], sequence[prefix + period]);
+            assert!((0..prefix).all(|i| sequence[i] != sequence[i + period]));
+            assert!((1..period).all(|i| sequence[prefix] != sequence[prefix + i]));
+            assert_eq!(brent(start, next_value), cycle);
+            assert_eq!(find_by_key(start, next_value, |x| *x), cycle);
+        }
+    }
+
+    #[test]
+    fn fingerprint_test() {
+        // the counter keeps growing, but only its remainder decides what comes next
+        let cycle = find_by_key(3usize, |x| x + 2, |x| x % 8);
+
+        assert_eq!(cycle, Cycle::new(0, 4));
+        assert_eq!(cycle.get_equivalent_step(4), 0);
+        assert_eq!(cycle.get_equivalent_step(11), 3);
+
+        let mut detector = CycleDetector::new();
+
+        assert_eq!(detector.push('a'), None);
+        assert_eq!(detector.push('b'), None);
+        assert_eq!(detector.push('c'), None);
+        assert_eq!(detector.push('b'), Some(Cycle::new(1, 2)));
+        assert_eq!(detector.get_steps(), 3);
+    }
+
+    #[test]
+    fn extrapolate_test() {
+        let sequence = get_sequence(7, 1000);
+        let sums = std::iter::once(0)
+            .chain(sequence.iter().scan(0, |sum, x| {
+                *sum += x;
+                Some(*sum)
+            }))
+            .collect::<Vec<usize>>();
+        let cycle = brent(7, next_value);
+        let known = cycle.get_prefix() + cycle.get_period();
+
+        (0..sums.len()).for_each(|n| {
+            let value = cycle.extrapolate(n, |i| {
+                assert!(i <= known);
+                sums[i]
+            });
+            assert_eq!(value, sums[n]);
+        });
+    }
+}
@@ -0,0 +1,307 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+    is_directed: bool,
+}
+
+// distances between nodes, indexed the same way as the graph they were calculated for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceMatrix {
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl DistanceMatrix {
+    pub fn get(&self, from: usize, to: usize) -> Option<usize> {
+        self.distances[from][to]
+    }
+
+    pub fn len(&self) -> usize {
+        self.distances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.distances.is_empty()
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new_directed() -> Graph<N> {
+        Graph {
+            nodes: vec![],
+            indices: HashMap::new(),
+            edges: vec![],
+            is_directed: true,
+        }
+    }
+
+    pub fn new_undirected() -> Graph<N> {
+        Graph {
+            is_directed: false,
+            ..Graph::new_directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.is_directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(i) = self.indices.get(&node) {
+            return *i;
+        }
+        self.nodes.push(node.clone());
+        self.edges.push(vec![]);
+        self.indices.insert(node, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    // adding an edge that already exists only updates its weight
+    pub fn add_edge(&mut self, from: N, to: N, weight: usize) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.insert_edge(from, to, weight);
+        if !self.is_directed {
+            self.insert_edge(to, from, weight);
+        }
+    }
+
+    fn insert_edge(&mut self, from: usize, to: usize, weight: usize) {
+        match self.edges[from].iter_mut().find(|(other, _)| *other == to) {
+            Some(edge) => edge.1 = weight,
+            None => self.edges[from].push((to, weight)),
+        }
+    }
+
+    pub fn get_nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn get_node(&self, i: usize) -> &N {
+        &self.nodes[i]
+    }
+
+    pub fn get_index(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    pub fn get_edges(&self, node: &N) -> impl Iterator<Item = (&N, usize)> {
+        self.get_index(node)
+            .map_or(&[][..], |i| &self.edges[i][..])
+            .iter()
+            .map(|(to, weight)| (&self.nodes[*to], *weight))
+    }
+
+    pub fn get_weight(&self, from: &N, to: &N) -> Option<usize> {
+        let to = self.get_index(to)?;
+        self.edges[self.get_index(from)?]
+            .iter()
+            .find(|(other, _)| *other == to)
+            .map(|(_, weight)| *weight)
+    }
+
+    pub fn floyd_warshall(&self) -> DistanceMatrix {
+        let mut distances = vec![vec![None; self.len()]; self.len()];
+        (0..self.len()).for_each(|i| distances[i][i] = Some(0));
+        self.edges.iter().enumerate().for_each(|(from, edges)| {
+            edges.iter().for_each(|(to, weight)| {
+                if from != *to {
+                    distances[from][*to] = Some(*weight);
+                }
+            })
+        });
+        for k in 0..self.len() {
+            for i in 0..self.len() {
+                for j in 0..self.len() {
+                    if let (Some(a), Some(b)) = (distances[i][k], distances[k][j]) {
+                        if distances[i][j].is_none_or(|current| a + b < current) {
+                            distances[i][j] = Some(a + b);
+                        }
+                    }
+                }
+            }
+        }
+        DistanceMatrix { distances }
+    }
+
+    fn get_distances_from_index(&self, start: usize) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        let mut heap = BinaryHeap::from([Reverse((0, start))]);
+        distances[start] = Some(0);
+        while let Some(Reverse((distance, i))) = heap.pop() {
+            if distances[i].is_some_and(|best| best < distance) {
+                continue;
+            }
+            self.edges[i].iter().for_each(|(to, weight)| {
+                let next_distance = distance + weight;
+                if distances[*to].is_none_or(|best| next_distance < best) {
+                    distances[*to] = Some(next_distance);
+                    heap.push(Reverse((next_distance, *to)));
+                }
+            });
+        }
+        distances
+    }
+
+    pub fn get_distances_from(&self, start: &N) -> HashMap<N, usize> {
+        let start = match self.get_index(start) {
+            Some(start) => start,
+            None => return HashMap::new(),
+        };
+        self.get_distances_from_index(start)
+            .iter()
+            .enumerate()
+            .filter_map(|(i, distance)| distance.map(|distance| (self.nodes[i].clone(), distance)))
+            .collect()
+    }
+
+    // better than Floyd-Warshall for sparse graphs
+    pub fn all_pairs_dijkstra(&self) -> DistanceMatrix {
+        DistanceMatrix {
+            distances: (0..self.len())
+                .map(|i| self.get_distances_from_index(i))
+                .collect(),
+        }
+    }
+
+    // keeps only the interesting nodes, each pair gets an edge weighted with the shortest distance between them
+    pub fn contract<F: Fn(&N) -> bool>(&self, is_interesting: F) -> Graph<N> {
+        let interesting = (0..self.len())
+            .filter(|i| is_interesting(&self.nodes[*i]))
+            .collect::<Vec<usize>>();
+        let mut contracted = if self.is_directed {
+            Graph::new_directed()
+        } else {
+            Graph::new_undirected()
+        };
+        interesting.iter().for_each(|i| {
+            contracted.add_node(self.nodes[*i].clone());
+        });
+        interesting.iter().for_each(|from| {
+            let distances = self.get_distances_from_index(*from);
+            interesting.iter().filter(|to| from != *to).for_each(|to| {
+                if let Some(distance) = distances[*to] {
+                    contracted.insert_edge(
+                        contracted.indices[&self.nodes[*from]],
+                        contracted.indices[&self.nodes[*to]],
+                        distance,
+                    );
+                }
+            });
+        });
+        contracted
+    }
+}
+
+impl<N: Clone + Eq + Hash + Display> Graph<N> {
+    pub fn to_dot(&self) -> String {
+        let (keyword, connector) = if self.is_directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let mut lines = vec![format!("{} {{", keyword)];
+        self.nodes.iter().enumerate().for_each(|(i, node)| {
+            lines.push(format!("    n{} [label=\"{}\"];", i, node));
+        });
+        self.edges.iter().enumerate().for_each(|(from, edges)| {
+            edges
+                .iter()
+                .filter(|(to, _)| self.is_directed || from <= *to)
+                .for_each(|(to, weight)| {
+                    lines.push(format!(
+                        "    n{} {} n{} [label=\"{}\"];",
+                        from, connector, to, weight
+                    ));
+                });
+        });
+        lines.push(String::from("}"));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a - b - c - d with a shortcut a - d, and an isolated e
+    fn get_graph() -> Graph<char> {
+        let mut graph = Graph::new_undirected();
+        graph.add_edge('a', 'b', 1);
+        graph.add_edge('b', 'c', 2);
+        graph.add_edge('c', 'd', 3);
+        graph.add_edge('a', 'd', 10);
+        graph.add_node('e');
+        graph
+    }
+
+    #[test]
+    fn shortest_paths_test() {
+        let graph = get_graph();
+        let (a, d, e) = (
+            graph.get_index(&'a').unwrap(),
+            graph.get_index(&'d').unwrap(),
+            graph.get_index(&'e').unwrap(),
+        );
+
+        let distances = graph.floyd_warshall();
+
+        assert_eq!(distances.get(a, d), Some(6));
+        assert_eq!(distances.get(d, a), Some(6));
+        assert_eq!(distances.get(a, e), None);
+        assert_eq!(distances.get(e, e), Some(0));
+        assert_eq!(distances, graph.all_pairs_dijkstra());
+        assert_eq!(graph.get_distances_from(&'c')[&'a'], 3);
+        assert_eq!(graph.get_distances_from(&'c').len(), 4);
+    }
+
+    #[test]
+    fn directed_test() {
+        let mut graph = Graph::new_directed();
+        graph.add_edge(1, 2, 5);
+        graph.add_edge(2, 3, 5);
+        graph.add_edge(1, 2, 4);
+
+        assert_eq!(graph.get_weight(&1, &2), Some(4));
+        assert_eq!(graph.get_weight(&2, &1), None);
+        assert_eq!(graph.get_distances_from(&1).get(&3), Some(&9));
+        assert_eq!(graph.get_distances_from(&3).get(&1), None);
+    }
+
+    #[test]
+    fn contraction_test() {
+        let graph = get_graph();
+
+        let contracted = graph.contract(|node| *node != 'b' && *node != 'c');
+
+        assert_eq!(contracted.get_nodes(), &['a', 'd', 'e']);
+        assert_eq!(contracted.get_weight(&'a', &'d'), Some(6));
+        assert_eq!(contracted.get_weight(&'d', &'a'), Some(6));
+        assert_eq!(contracted.get_edges(&'e').count(), 0);
+    }
+
+    #[test]
+    fn dot_test() {
+        let mut graph = Graph::new_undirected();
+        graph.add_edge("AA", "BB", 2);
+
+        assert_eq!(
+            graph.to_dot(),
+            "graph {\n    n0 [label=\"AA\"];\n    n1 [label=\"BB\"];\n    n0 -- n1 [label=\"2\"];\n}"
+        );
+    }
+}
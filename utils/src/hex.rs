@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::plane::Direction;
+
+// axial coordinates, the third cube coordinate is always s = -q - r
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Hex {
+    q: isize,
+    r: isize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    FlatTop,
+    PointyTop,
+}
+
+static AXIAL_DIRECTIONS: [(isize, isize); 6] = [(1, 0), (0, 1), (-1, 1), (-1, 0), (0, -1), (1, -1)];
+
+impl Hex {
+    pub fn new(q: isize, r: isize) -> Hex {
+        Hex { q, r }
+    }
+
+    pub fn from_cube(q: isize, r: isize, s: isize) -> Hex {
+        if q + r + s != 0 {
+            panic!("Cube coordinates ({}, {}, {}) don't sum up to 0", q, r, s);
+        }
+        Hex { q, r }
+    }
+
+    pub fn get_q(&self) -> isize {
+        self.q
+    }
+
+    pub fn get_r(&self) -> isize {
+        self.r
+    }
+
+    pub fn get_s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    pub fn to_cube(&self) -> (isize, isize, isize) {
+        (self.q, self.r, self.get_s())
+    }
+
+    // offsets of all neighbours, going clockwise
+    pub fn get_directions() -> [Hex; 6] {
+        AXIAL_DIRECTIONS.map(|(q, r)| Hex::new(q, r))
+    }
+
+    pub fn neighbours(&self) -> [Hex; 6] {
+        Hex::get_directions().map(|offset| *self + offset)
+    }
+
+    pub fn length(&self) -> usize {
+        self.q
+            .unsigned_abs()
+            .max(self.r.unsigned_abs())
+            .max(self.get_s().unsigned_abs())
+    }
+
+    pub fn distance(&self, other: &Hex) -> usize {
+        (*self - *other).length()
+    }
+
+    // rotates by 60 degrees around the origin, Right is clockwise on screen in both layouts
+    pub fn rotate(&self, dir: &Direction) -> Hex {
+        let (q, r, s) = self.to_cube();
+        match dir {
+            Direction::Right => Hex::from_cube(-r, -s, -q),
+            Direction::Left => Hex::from_cube(-s, -q, -r),
+            _ => panic!("Hexes can only be rotated Left or Right"),
+        }
+    }
+
+    pub fn rotate_around(&self, center: &Hex, dir: &Direction) -> Hex {
+        *center + (*self - *center).rotate(dir)
+    }
+
+    pub fn ring(&self, radius: usize) -> Vec<Hex> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let directions = Hex::get_directions();
+        let mut hex = *self + directions[4] * radius as isize;
+        let mut ring = vec![];
+        for dir in directions {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += dir;
+            }
+        }
+        ring
+    }
+
+    pub fn spiral(&self, radius: usize) -> Vec<Hex> {
+        (0..=radius).flat_map(|i| self.ring(i)).collect()
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Hex) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<isize> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: isize) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl Neg for Hex {
+    type Output = Hex;
+
+    fn neg(self) -> Hex {
+        Hex::new(-self.q, -self.r)
+    }
+}
+
+impl HexDirection {
+    pub fn parse(input: &str) -> Option<HexDirection> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "n" => Some(HexDirection::North),
+            "ne" => Some(HexDirection::NorthEast),
+            "e" => Some(HexDirection::East),
+            "se" => Some(HexDirection::SouthEast),
+            "s" => Some(HexDirection::South),
+            "sw" => Some(HexDirection::SouthWest),
+            "w" => Some(HexDirection::West),
+            "nw" => Some(HexDirection::NorthWest),
+            _ => None,
+        }
+    }
+
+    // parses unseparated paths like "esenee", two letter directions take precedence
+    pub fn parse_path(input: &str) -> Option<Vec<HexDirection>> {
+        let mut path = vec![];
+        let mut rest = input.trim();
+        while !rest.is_empty() {
+            let dir = rest
+                .get(..2)
+                .and_then(HexDirection::parse)
+                .map(|dir| (dir, 2))
+                .or_else(|| rest.get(..1).and_then(HexDirection::parse).map(|dir| (dir, 1)))?;
+            path.push(dir.0);
+            rest = &rest[dir.1..];
+        }
+        Some(path)
+    }
+}
+
+impl Layout {
+    // the six compass directions valid in this layout, in the same clockwise order as Hex::get_directions
+    pub fn get_directions(&self) -> &'static [HexDirection; 6] {
+        static FLAT_TOP: [HexDirection; 6] = [
+            HexDirection::SouthEast,
+            HexDirection::South,
+            HexDirection::SouthWest,
+            HexDirection::NorthWest,
+            HexDirection::North,
+            HexDirection::NorthEast,
+        ];
+        static POINTY_TOP: [HexDirection; 6] = [
+            HexDirection::East,
+            HexDirection::SouthEast,
+            HexDirection::SouthWest,
+            HexDirection::West,
+            HexDirection::NorthWest,
+            HexDirection::NorthEast,
+        ];
+        match self {
+            Layout::FlatTop => &FLAT_TOP,
+            Layout::PointyTop => &POINTY_TOP,
+        }
+    }
+
+    pub fn get_offset(&self, dir: &HexDirection) -> Hex {
+        let i = self
+            .get_directions()
+            .iter()
+            .position(|other| other == dir)
+            .unwrap_or_else(|| panic!("{:?} isn't a valid direction in {:?} layout", dir, self));
+        Hex::get_directions()[i]
+    }
+
+    pub fn get_direction(&self, offset: &Hex) -> Option<HexDirection> {
+        Hex::get_directions()
+            .iter()
+            .position(|other| other == offset)
+            .map(|i| self.get_directions()[i])
+    }
+
+    pub fn to_pixel(&self, hex: &Hex, size: f64) -> (f64, f64) {
+        let (q, r) = (hex.q as f64, hex.r as f64);
+        let sqrt3 = 3f64.sqrt();
+        match self {
+            Layout::FlatTop => (size * 1.5 * q, size * sqrt3 * (r + q / 2.0)),
+            Layout::PointyTop => (size * sqrt3 * (q + r / 2.0), size * 1.5 * r),
+        }
+    }
+
+    // doubled coordinates, so that every hex lands on its own character cell
+    fn to_text_position(self, hex: &Hex) -> (isize, isize) {
+        match self {
+            Layout::FlatTop => (2 * hex.r + hex.q, hex.q),
+            Layout::PointyTop => (hex.r, 2 * hex.q + hex.r),
+        }
+    }
+
+    pub fn render<'a, I, F>(&self, hexes: I, render_hex: F) -> String
+    where
+        I: IntoIterator<Item = &'a Hex>,
+        F: Fn(&Hex) -> char,
+    {
+        let cells = hexes
+            .into_iter()
+            .map(|hex| (self.to_text_position(hex), render_hex(hex)))
+            .collect::<HashMap<(isize, isize), char>>();
+        let (min_y, max_y, min_x, max_x) = cells.keys().fold(
+            (isize::MAX, isize::MIN, isize::MAX, isize::MIN),
+            |(min_y, max_y, min_x, max_x), (y, x)| {
+                (min_y.min(*y), max_y.max(*y), min_x.min(*x), max_x.max(*x))
+            },
+        );
+        (min_y..=max_y)
+            .map(|y| {
+                (min_x..=max_x)
+                    .map(|x| *cells.get(&(y, x)).unwrap_or(&' '))
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_test() {
+        let origin = Hex::default();
+
+        assert!(origin.neighbours().iter().all(|hex| hex.length() == 1));
+        assert_eq!(Hex::from_cube(3, -1, -2).distance(&origin), 3);
+        assert_eq!(Hex::new(2, 2).distance(&Hex::new(-1, 3)), 3);
+    }
+
+    #[test]
+    fn rotation_test() {
+        let hex = Hex::new(2, -1);
+
+        let rotated = (0..6).fold(hex, |hex, _| hex.rotate(&Direction::Right));
+
+        assert_eq!(rotated, hex);
+        assert_eq!(hex.rotate(&Direction::Right).rotate(&Direction::Left), hex);
+        assert_eq!(Hex::new(1, 0).rotate(&Direction::Right), Hex::new(0, 1));
+        assert_eq!(
+            Hex::new(3, 0).rotate_around(&Hex::new(2, 0), &Direction::Left),
+            Hex::new(3, -1)
+        );
+    }
+
+    #[test]
+    fn ring_and_spiral_test() {
+        let center = Hex::new(1, -2);
+
+        assert_eq!(center.ring(0), vec![center]);
+        assert_eq!(center.ring(3).len(), 18);
+        assert!(center.ring(3).iter().all(|hex| hex.distance(&center) == 3));
+        assert_eq!(center.spiral(2).len(), 19);
+    }
+
+    #[test]
+    fn parsing_test() {
+        let path = HexDirection::parse_path("esenee").unwrap();
+
+        let end = path
+            .iter()
+            .fold(Hex::default(), |hex, dir| hex + Layout::PointyTop.get_offset(dir));
+
+        assert_eq!(end, Hex::new(3, 0));
+        assert_eq!(HexDirection::parse_path("ex"), None);
+        assert_eq!(
+            "ne,ne,sw,sw"
+                .split(',')
+                .map(|dir| Layout::FlatTop.get_offset(&HexDirection::parse(dir).unwrap()))
+                .fold(Hex::default(), |hex, offset| hex + offset),
+            Hex::default()
+        );
+    }
+
+    #[test]
+    fn layout_test() {
+        let spiral = Hex::default().spiral(1);
+
+        assert_eq!(
+            Layout::PointyTop.render(spiral.iter(), |_| '#'),
+            " # #\n# # #\n # #"
+        );
+        assert_eq!(
+            Layout::FlatTop.render(spiral.iter(), |_| '#'),
+            " #\n# #\n #\n# #\n #"
+        );
+        assert_eq!(
+            Layout::FlatTop.get_direction(&Hex::new(0, -1)),
+            Some(HexDirection::North)
+        );
+    }
+}
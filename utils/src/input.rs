@@ -0,0 +1,107 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor};
+use std::path::Path;
+
+use memmap2::Mmap;
+
+// puzzle input that doesn't have to be loaded into memory all at once,
+// solvers with a `from_reader` constructor can go through it line by line
+pub enum Input {
+    Text(String),
+    Mapped(Mmap),
+    Stream(Box<dyn BufRead>),
+}
+
+impl Input {
+    pub fn open(path: &Path) -> io::Result<Input> {
+        let file = File::open(path)?;
+        if file.metadata()?.len() == 0 {
+            return Ok(Input::Text(String::new()));
+        }
+        // the file shouldn't be modified while it's mapped, which is fine for generated or cached inputs
+        let mmap = unsafe { Mmap::map(&file)? };
+        Ok(Input::Mapped(mmap))
+    }
+
+    pub fn from_reader<R: BufRead + 'static>(reader: R) -> Input {
+        Input::Stream(Box::new(reader))
+    }
+
+    pub fn into_reader(self) -> Box<dyn BufRead> {
+        match self {
+            Input::Text(text) => Box::new(Cursor::new(text)),
+            Input::Mapped(mmap) => Box::new(Cursor::new(mmap)),
+            Input::Stream(reader) => reader,
+        }
+    }
+
+    // for solvers that need all of it anyway
+    pub fn into_string(self) -> String {
+        match self {
+            Input::Text(text) => text,
+            Input::Mapped(mmap) => std::str::from_utf8(&mmap)
+                .expect("Puzzle input isn't valid UTF-8")
+                .to_owned(),
+            Input::Stream(mut reader) => {
+                let mut text = String::new();
+                reader
+                    .read_to_string(&mut text)
+                    .expect("Can't read puzzle input");
+                text
+            }
+        }
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Self {
+        Input::Text(text)
+    }
+}
+
+impl From<File> for Input {
+    fn from(file: File) -> Self {
+        Input::from_reader(BufReader::new(file))
+    }
+}
+
+pub fn read_lines<R: BufRead>(reader: R) -> impl Iterator<Item = String> {
+    reader
+        .lines()
+        .map(|line| line.expect("Can't read puzzle input"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn input_test() {
+        let path = std::env::temp_dir().join(format!("aoc22-input-test-{}", std::process::id()));
+        fs::write(&path, "1,2\n3,4\n").unwrap();
+
+        let mapped = Input::open(&path).unwrap();
+
+        assert!(matches!(mapped, Input::Mapped(_)));
+        assert_eq!(mapped.into_string(), "1,2\n3,4\n");
+        assert_eq!(
+            Input::from(File::open(&path).unwrap()).into_string(),
+            "1,2\n3,4\n"
+        );
+        assert_eq!(
+            read_lines(Input::open(&path).unwrap().into_reader()).collect::<Vec<String>>(),
+            vec!["1,2", "3,4"]
+        );
+
+        fs::write(&path, "").unwrap();
+        let empty = Input::open(&path).unwrap().into_string();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(empty, "");
+        assert_eq!(
+            read_lines(Input::from(String::from("a\r\nb")).into_reader()).collect::<Vec<String>>(),
+            vec!["a", "b"]
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+pub fn get_checksum(input: &str) -> String {
+    let hash = input.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn get_checksum_path(input_path: &Path) -> PathBuf {
+    let mut path = input_path.as_os_str().to_owned();
+    path.push(".fnv1a");
+    PathBuf::from(path)
+}
+
+pub fn save_checksum(input_path: &Path, input: &str) {
+    fs::write(get_checksum_path(input_path), get_checksum(input))
+        .expect("Can't create puzzle input checksum file");
+}
+
+pub fn is_checksum_matching(input_path: &Path, input: &str) -> Option<bool> {
+    let checksum = fs::read_to_string(get_checksum_path(input_path)).ok()?;
+    Some(checksum.trim() == get_checksum(input))
+}
+
+pub fn find_issues(input: &str) -> Vec<&'static str> {
+    let mut issues = vec![];
+    if input.starts_with('\u{feff}') {
+        issues.push("byte order mark at the start of the file");
+    }
+    if input.contains('\r') {
+        issues.push("CRLF line endings");
+    }
+    if input
+        .split('\n')
+        .any(|line| !line.is_empty() && line.trim().is_empty())
+    {
+        issues.push("whitespace on blank lines");
+    }
+    if !input.is_empty() && !input.ends_with('\n') {
+        issues.push("missing final newline");
+    }
+    issues
+}
+
+// trailing whitespace on non-blank lines is left alone, day5 and day22 maps depend on the padding
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = input
+        .split('\n')
+        .map(|line| {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if line.trim().is_empty() {
+                ""
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join("\n");
+    if !normalized.is_empty() && !normalized.ends_with('\n') {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_test() {
+        assert_eq!(get_checksum(""), "cbf29ce484222325");
+        assert_eq!(get_checksum("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn issues_test() {
+        assert!(find_issues("1\n2\n\n3\n").is_empty());
+        assert_eq!(
+            find_issues("1\r\n \r\n2"),
+            vec![
+                "CRLF line endings",
+                "whitespace on blank lines",
+                "missing final newline"
+            ]
+        );
+    }
+
+    #[test]
+    fn normalization_test() {
+        let input = "\u{feff}[1,1]\r\n[2]\r\n  \r\n    [D]    \r\n[3]";
+
+        let normalized = normalize(input);
+
+        assert_eq!(normalized, "[1,1]\n[2]\n\n    [D]    \n[3]\n");
+        assert!(find_issues(&normalized).is_empty());
+        assert_eq!(normalized.split_once("\n\n").unwrap().0, "[1,1]\n[2]");
+    }
+}
@@ -0,0 +1,287 @@
+use std::fmt;
+
+// both ends are stored inclusive, so that intervals reaching isize::MAX can be represented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    start: isize,
+    last: isize,
+}
+
+// sorted, disjoint and never touching intervals
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl Interval {
+    pub fn new(start: isize, end: isize) -> Option<Interval> {
+        if end <= start {
+            return None;
+        }
+        Some(Interval {
+            start,
+            last: end - 1,
+        })
+    }
+
+    pub fn inclusive(start: isize, last: isize) -> Option<Interval> {
+        if last < start {
+            return None;
+        }
+        Some(Interval { start, last })
+    }
+
+    pub fn get_start(&self) -> isize {
+        self.start
+    }
+
+    pub fn get_last(&self) -> isize {
+        self.last
+    }
+
+    // None when the interval reaches isize::MAX
+    pub fn get_end(&self) -> Option<isize> {
+        self.last.checked_add(1)
+    }
+
+    // both ends are inclusive, so an interval is never empty
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u128 {
+        self.last.abs_diff(self.start) as u128 + 1
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        self.start <= value && value <= self.last
+    }
+
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.last <= self.last
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.last && other.start <= self.last
+    }
+
+    // overlapping or adjacent, saturating_add keeps isize::MAX from overflowing
+    pub fn touches(&self, other: &Interval) -> bool {
+        self.start <= other.last.saturating_add(1) && other.start <= self.last.saturating_add(1)
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::inclusive(self.start.max(other.start), self.last.min(other.last))
+    }
+
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval {
+            start: self.start.min(other.start),
+            last: self.last.max(other.last),
+        }
+    }
+
+    pub fn difference(&self, other: &Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        let mut pieces = vec![];
+        if self.start < other.start {
+            pieces.push(Interval {
+                start: self.start,
+                last: other.start - 1,
+            });
+        }
+        if other.last < self.last {
+            pieces.push(Interval {
+                start: other.last + 1,
+                last: self.last,
+            });
+        }
+        pieces
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.last)
+    }
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn get_intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(|interval| interval.len()).sum()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let from = self
+            .intervals
+            .partition_point(|other| other.last.saturating_add(1) < interval.start);
+        let to = self
+            .intervals
+            .partition_point(|other| other.start <= interval.last.saturating_add(1));
+        let merged = self.intervals[from..to]
+            .iter()
+            .fold(interval, |merged, other| merged.hull(other));
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: &Interval) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|other| other.difference(interval))
+            .collect();
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.last < value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.last < other.start);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains_interval(other))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        other
+            .intervals
+            .iter()
+            .for_each(|interval| union.insert(*interval));
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+            if let Some(intersection) = a.intersection(b) {
+                intervals.push(intersection);
+            }
+            if a.last < b.last {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        other
+            .intervals
+            .iter()
+            .for_each(|interval| difference.remove(interval));
+        difference
+    }
+
+    // uncovered intervals between the first and the last covered value
+    pub fn gaps(&self) -> Vec<Interval> {
+        self.intervals
+            .windows(2)
+            .map(|pair| Interval {
+                start: pair[0].last + 1,
+                last: pair[1].start - 1,
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|interval| set.insert(interval));
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: isize, last: isize) -> Interval {
+        Interval::inclusive(start, last).unwrap()
+    }
+
+    #[test]
+    fn interval_test() {
+        assert_eq!(Interval::new(2, 2), None);
+        assert_eq!(Interval::new(2, 5), Interval::inclusive(2, 4));
+        assert_eq!(interval(2, 4).len(), 3);
+        assert!(interval(2, 8).contains_interval(&interval(3, 7)));
+        assert!(interval(5, 7).overlaps(&interval(7, 9)));
+        assert!(!interval(2, 4).overlaps(&interval(5, 6)));
+        assert!(interval(2, 4).touches(&interval(5, 6)));
+        assert_eq!(
+            interval(0, 9).difference(&interval(3, 4)),
+            vec![interval(0, 2), interval(5, 9)]
+        );
+    }
+
+    #[test]
+    fn extreme_values_test() {
+        let everything = interval(isize::MIN, isize::MAX);
+
+        assert_eq!(everything.len(), 1 << 64);
+        assert_eq!(everything.get_end(), None);
+        assert!(interval(0, isize::MAX).touches(&interval(isize::MIN, -1)));
+        assert_eq!(
+            everything.difference(&interval(isize::MIN, isize::MAX - 1)),
+            vec![interval(isize::MAX, isize::MAX)]
+        );
+    }
+
+    #[test]
+    fn normalization_test() {
+        let set = [(11, 12), (0, 7), (-3, 0), (7, 8), (2, 5), (4, 10), (11, 13)]
+            .iter()
+            .filter_map(|(start, end)| Interval::new(*start, *end))
+            .collect::<IntervalSet>();
+
+        assert_eq!(set.get_intervals(), &[interval(-3, 9), interval(11, 12)]);
+        assert_eq!(set.gaps(), vec![interval(10, 10)]);
+        assert_eq!(set.len(), 15);
+        assert!(set.contains(12));
+        assert!(!set.contains(10));
+        assert!(set.contains_interval(&interval(0, 9)));
+        assert!(!set.contains_interval(&interval(9, 11)));
+    }
+
+    #[test]
+    fn set_operations_test() {
+        let a = IntervalSet::from_iter([interval(0, 5), interval(10, 15)]);
+        let b = IntervalSet::from_iter([interval(4, 11), interval(14, 20)]);
+
+        assert_eq!(a.union(&b).get_intervals(), &[interval(0, 20)]);
+        assert_eq!(
+            a.intersection(&b).get_intervals(),
+            &[interval(4, 5), interval(10, 11), interval(14, 15)]
+        );
+        assert_eq!(
+            a.difference(&b).get_intervals(),
+            &[interval(0, 3), interval(12, 13)]
+        );
+        assert_eq!(a.difference(&a), IntervalSet::new());
+    }
+}
@@ -0,0 +1,292 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// puzzles unlock at midnight EST, which is 05:00 UTC
+const UNLOCK_OFFSET: i64 = 5 * 3600;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub global_score: u32,
+    pub last_star_ts: i64,
+    pub completion_day_level: HashMap<u32, HashMap<u32, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    pub star_index: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct StarEvent {
+    pub day: u32,
+    pub part: u32,
+    pub timestamp: i64,
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+pub fn get_unlock_timestamp(year: u32, day: u32) -> i64 {
+    days_from_civil(year as i64, 12, day as i64) * 86400 + UNLOCK_OFFSET
+}
+
+pub fn format_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+    if days > 0 {
+        format!("{}{}d {}", sign, days, time)
+    } else {
+        format!("{}{}", sign, time)
+    }
+}
+
+impl Member {
+    pub fn get_display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn get_star(&self, day: u32, part: u32) -> Option<&Star> {
+        self.completion_day_level
+            .get(&day)
+            .and_then(|parts| parts.get(&part))
+    }
+
+    pub fn get_delta_time(&self, day: u32) -> Option<i64> {
+        let first_star = self.get_star(day, 1)?;
+        let second_star = self.get_star(day, 2)?;
+        Some(second_star.get_star_ts - first_star.get_star_ts)
+    }
+
+    pub fn get_star_history(&self) -> Vec<StarEvent> {
+        let mut history = self
+            .completion_day_level
+            .iter()
+            .flat_map(|(day, parts)| {
+                parts.iter().map(|(part, star)| StarEvent {
+                    day: *day,
+                    part: *part,
+                    timestamp: star.get_star_ts,
+                })
+            })
+            .collect::<Vec<StarEvent>>();
+        history.sort_by_key(|event| (event.timestamp, event.day, event.part));
+        history
+    }
+}
+
+impl Leaderboard {
+    pub fn get_year(&self) -> u32 {
+        self.event.parse().unwrap_or(0)
+    }
+
+    pub fn get_standings(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<&Member>>();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+
+    pub fn render_standings(&self) -> String {
+        let standings = self.get_standings();
+        let name_width = standings
+            .iter()
+            .map(|member| member.get_display_name().chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut output = format!(
+            "{:>4} {:>5} {:>5}  {:<name_width$}  {}\n",
+            "#", "score", "stars", "name", "         1111111111222222"
+        );
+        output += &format!(
+            "{:>4} {:>5} {:>5}  {:<name_width$}  {}\n",
+            "", "", "", "", "1234567890123456789012345"
+        );
+        standings.iter().enumerate().for_each(|(i, member)| {
+            let stars = (1..=25)
+                .map(|day| {
+                    match (member.get_star(day, 1), member.get_star(day, 2)) {
+                        (Some(_), Some(_)) => '*',
+                        (Some(_), None) => '.',
+                        _ => ' ',
+                    }
+                })
+                .collect::<String>();
+            output += &format!(
+                "{:>3}) {:>5} {:>5}  {:<name_width$}  {}\n",
+                i + 1,
+                member.local_score,
+                member.stars,
+                member.get_display_name(),
+                stars.trim_end()
+            );
+        });
+        output
+    }
+
+    pub fn render_delta_times(&self) -> String {
+        let mut output = String::new();
+        for day in 1..=25 {
+            let mut deltas = self
+                .members
+                .values()
+                .filter_map(|member| member.get_delta_time(day).map(|delta| (delta, member)))
+                .collect::<Vec<(i64, &Member)>>();
+            if deltas.is_empty() {
+                continue;
+            }
+            deltas.sort_by_key(|(delta, member)| (*delta, member.id));
+            output += &format!("Day {}\n", day);
+            deltas.iter().for_each(|(delta, member)| {
+                output += &format!(
+                    "  {:>12}  {}\n",
+                    format_duration(*delta),
+                    member.get_display_name()
+                );
+            });
+        }
+        output
+    }
+
+    pub fn render_star_history(&self, member: &Member) -> String {
+        let year = self.get_year();
+        let mut output = format!("{}\n", member.get_display_name());
+        member.get_star_history().iter().for_each(|event| {
+            output += &format!(
+                "  day {:>2} part {}  {:>12} after unlock\n",
+                event.day,
+                event.part,
+                format_duration(event.timestamp - get_unlock_timestamp(year, event.day))
+            );
+        });
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> &'static str {
+        r#"{
+            "event": "2022",
+            "owner_id": 1,
+            "members": {
+                "1": {
+                    "id": 1,
+                    "name": "alice",
+                    "stars": 3,
+                    "local_score": 10,
+                    "global_score": 0,
+                    "last_star_ts": 1669961400,
+                    "completion_day_level": {
+                        "1": {
+                            "1": { "get_star_ts": 1669871100, "star_index": 1 },
+                            "2": { "get_star_ts": 1669871400, "star_index": 2 }
+                        },
+                        "2": {
+                            "1": { "get_star_ts": 1669961400, "star_index": 5 }
+                        }
+                    }
+                },
+                "2": {
+                    "id": 2,
+                    "name": null,
+                    "stars": 2,
+                    "local_score": 7,
+                    "global_score": 0,
+                    "last_star_ts": 1669875000,
+                    "completion_day_level": {
+                        "1": {
+                            "1": { "get_star_ts": 1669872000, "star_index": 3 },
+                            "2": { "get_star_ts": 1669875000, "star_index": 4 }
+                        }
+                    }
+                }
+            }
+        }"#
+    }
+
+    #[test]
+    fn unlock_timestamp_test() {
+        assert_eq!(get_unlock_timestamp(2022, 1), 1669870800);
+    }
+
+    #[test]
+    fn duration_formatting_test() {
+        assert_eq!(format_duration(3723), "01:02:03");
+        assert_eq!(format_duration(90061), "1d 01:01:01");
+    }
+
+    #[test]
+    fn standings_test() {
+        let leaderboard: Leaderboard = serde_json::from_str(get_input()).unwrap();
+
+        let standings = leaderboard
+            .get_standings()
+            .iter()
+            .map(|member| member.get_display_name())
+            .collect::<Vec<String>>();
+
+        assert_eq!(standings, vec!["alice", "(anonymous user #2)"]);
+    }
+
+    #[test]
+    fn delta_time_test() {
+        let leaderboard: Leaderboard = serde_json::from_str(get_input()).unwrap();
+        let alice = &leaderboard.members["1"];
+
+        assert_eq!(alice.get_delta_time(1), Some(300));
+        assert_eq!(alice.get_delta_time(2), None);
+        assert_eq!(leaderboard.members["2"].get_delta_time(1), Some(3000));
+    }
+
+    #[test]
+    fn star_history_test() {
+        let leaderboard: Leaderboard = serde_json::from_str(get_input()).unwrap();
+
+        let history = leaderboard.members["1"].get_star_history();
+
+        assert_eq!(
+            history,
+            vec![
+                StarEvent { day: 1, part: 1, timestamp: 1669871100 },
+                StarEvent { day: 1, part: 2, timestamp: 1669871400 },
+                StarEvent { day: 2, part: 1, timestamp: 1669961400 },
+            ]
+        );
+    }
+}
@@ -1,8 +1,22 @@
 extern crate reqwest;
 
+pub mod config;
+pub mod cycle;
 pub mod day;
+pub mod graph;
+pub mod hex;
+pub mod input;
+pub mod integrity;
+pub mod interval;
+pub mod leaderboard;
 pub mod network;
+pub mod optimize;
+pub mod parse;
 pub mod plane;
+pub mod random;
+pub mod render;
+pub mod search;
+pub mod space;
 
 pub use day::ChallengeSolver;
 
@@ -11,6 +25,18 @@ pub fn extract_integers_from_string<T: std::str::FromStr>(input: &String) -> Vec
         .lines()
         .map(|line| {
             line.split(|c: char| !c.is_digit(10) && c != '-')
+                .flat_map(|token| {
+                    // a minus right after a number is a separator ("2-4" is a range),
+                    // otherwise it's the sign of whatever follows it
+                    token.split('-').scan(false, |is_signed, part| {
+                        let number = match is_signed {
+                            true => format!("-{}", part),
+                            false => part.to_string(),
+                        };
+                        *is_signed = part.is_empty();
+                        Some(number)
+                    })
+                })
                 .filter_map(|s| s.parse().ok())
                 .collect()
         }).collect()
@@ -28,4 +54,50 @@ pub fn extract_unsigned_integers_from_string(input: &String) -> Vec<Vec<usize>>
 
 pub fn get_char_arrays(input: &String) -> Vec<Vec<char>> {
         input.lines().filter(|line| !line.is_empty()).map(|line| line.trim().chars().collect::<Vec<char>>()).collect()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn extract_integers_test() {
+        let input = String::from(
+            "Sensor at x=-2, y=15: closest beacon is at x=3, y=-10\n2-4,6-8\n\n--5 -- a-b 7-",
+        );
+
+        let result: Vec<Vec<isize>> = extract_integers_from_string(&input);
+
+        assert_eq!(result, vec![vec![-2, 15, 3, -10], vec![2, 4, 6, 8], vec![], vec![-5, 7]]);
+    }
+
+    proptest! {
+        #[test]
+        fn extracts_joined_numbers(
+            numbers in prop::collection::vec(any::<i64>(), 0..20),
+            separators in prop::collection::vec("[ ,:=a-z]{1,3}", 20),
+        ) {
+            let line = numbers
+                .iter()
+                .zip(separators.iter())
+                .map(|(num, sep)| format!("{}{}", num, sep))
+                .collect::<String>();
+
+            let result: Vec<Vec<i64>> = extract_integers_from_string(&line);
+
+            prop_assert_eq!(result.concat(), numbers);
+        }
+
+        #[test]
+        fn extracts_ranges_as_unsigned(a in any::<u32>(), b in any::<u32>()) {
+            let line = format!("{}-{}", a, b);
+
+            let signed: Vec<Vec<i64>> = extract_integers_from_string(&line);
+            let unsigned = extract_unsigned_integers_from_string(&line);
+
+            prop_assert_eq!(&signed, &vec![vec![a as i64, b as i64]]);
+            prop_assert_eq!(&unsigned, &vec![vec![a as usize, b as usize]]);
+        }
+    }
+}
@@ -1,62 +1,371 @@
 extern crate reqwest;
 
 use reqwest::header::COOKIE;
-use std::env;
+use reqwest::StatusCode;
+use std::fmt;
 use std::fs;
 use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
 
-fn fetch_input_for_day(year: &u32, day: &u32, session: &str) -> String {
-    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-    let client = reqwest::Client::new();
-
-    let tokio_runtime = tokio::runtime::Builder::new_current_thread()
-        .enable_all()
-        .build()
-        .unwrap();
-    
-    let input = tokio_runtime.block_on(async move { client
-        .get(&url)
-        .header(COOKIE, format!("session={}", session))
-        .send()
-        .await
-        .unwrap()
-        .text()
-        .await
-        .unwrap() 
-    });
-    if input.contains("Not Found") || input.contains("Please don't repeatedly request this endpoint before it unlocks!") {
-        panic!("Puzzle for day {} is not live yet", day);
+use crate::config::Config;
+use crate::integrity;
+use crate::leaderboard::{get_unlock_timestamp, Leaderboard};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const LEADERBOARD_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const BULK_FETCH_DELAY: Duration = Duration::from_secs(3);
+const USER_AGENT: &str = concat!(
+    "aoc22-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/pitpo/aoc22; personal input cache)"
+);
+
+#[derive(Debug)]
+enum FetchError {
+    Unauthorized,
+    Status(StatusCode),
+    Failed(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::Unauthorized => write!(f, "Session cookie is invalid"),
+            FetchError::Status(status) => write!(f, "Server responded with {}", status),
+            FetchError::Failed(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(err: reqwest::Error) -> FetchError {
+        FetchError::Failed(err.to_string())
+    }
+}
+
+struct Client {
+    client: reqwest::Client,
+    tokio_runtime: tokio::runtime::Runtime,
+    session: String,
+    base_url: String,
+}
+
+impl Client {
+    fn new(session: String) -> Client {
+        let client = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .unwrap();
+        let tokio_runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        Client {
+            client,
+            tokio_runtime,
+            session,
+            base_url: String::from(BASE_URL),
+        }
+    }
+
+    fn fetch_url(&self, url: &str) -> Result<String, FetchError> {
+        let request = self
+            .client
+            .get(url)
+            .header(COOKIE, format!("session={}", self.session));
+        self.tokio_runtime.block_on(async move {
+            let response = request.send().await?;
+            // an expired session either gets refused outright or redirected to the login page
+            if matches!(response.status(), StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+                || response.url().path().starts_with("/auth")
+            {
+                return Err(FetchError::Unauthorized);
+            }
+            // error pages must never end up in the cache as puzzle input
+            if !response.status().is_success() {
+                return Err(FetchError::Status(response.status()));
+            }
+            Ok(response.text().await?)
+        })
+    }
+
+    fn fetch_input_for_day(&self, year: u32, day: u32) -> Result<String, FetchError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let input = self.fetch_url(&url).map_err(|err| match err {
+            FetchError::Status(StatusCode::NOT_FOUND) => {
+                FetchError::Failed(format!("Puzzle for day {} is not live yet", day))
+            }
+            err => err,
+        })?;
+        if input.contains("Not Found") || input.contains("Please don't repeatedly request this endpoint before it unlocks!") {
+            return Err(FetchError::Failed(format!("Puzzle for day {} is not live yet", day)));
+        }
+        if input.contains("log in") {
+            return Err(FetchError::Unauthorized);
+        }
+        Ok(input)
+    }
+}
+
+fn save_to_cache(input_path: &Path, input: &str) {
+    if let Some(input_dir) = input_path.parent() {
+        fs::create_dir_all(input_dir).expect("Can't create directory for puzzle input files");
+    }
+    fs::write(input_path, input).expect("Can't create puzzle input file");
+}
+
+fn save_input(input_path: &Path, input: &str) {
+    save_to_cache(input_path, input);
+    integrity::save_checksum(input_path, input);
+}
+
+fn fetch_and_save(
+    client: &Client,
+    year: u32,
+    day: u32,
+    input_path: &Path,
+) -> Result<String, FetchError> {
+    let input = client.fetch_input_for_day(year, day)?;
+    save_input(input_path, &input);
+    Ok(input)
+}
+
+fn verify_cached_input(config: &Config, input_path: &Path, input: String) -> String {
+    if integrity::is_checksum_matching(input_path, &input) == Some(false) {
+        eprintln!(
+            "Warning: \"{}\" was modified since it was downloaded, delete it to fetch it again",
+            input_path.display()
+        );
+    }
+    let issues = integrity::find_issues(&input);
+    if issues.is_empty() {
+        return input;
     }
-    if input.contains("log in") {
-        panic!("Session cookie is invalid");
+    if config.normalize {
+        return integrity::normalize(&input);
     }
+    eprintln!(
+        "Warning: \"{}\" has {}, pass --normalize to clean it up when loading",
+        input_path.display(),
+        issues.join(", ")
+    );
     input
 }
 
-fn save_input(input_path: &str, input: &str) {
-    let input_dir = input_path.clone().split("/").nth(0).unwrap_or(".");
-    if !Path::new(input_dir).is_dir() {
-        fs::create_dir(input_dir).expect("Can't create directory for puzzle input files");
+// copy inputs cached by older versions into the new location instead of fetching them again
+fn migrate_legacy_input(year: u32, day: u32, input_path: &Path) {
+    let legacy_path = Config::get_legacy_input_path(day);
+    if year != 2022 || input_path.is_file() || !legacy_path.is_file() {
+        return;
     }
-    fs::write(input_path, &input).expect("Can't create puzzle input file");
+    if let Some(input_dir) = input_path.parent() {
+        fs::create_dir_all(input_dir).expect("Can't create directory for puzzle input files");
+    }
+    fs::copy(&legacy_path, input_path).expect("Can't copy cached puzzle input file");
+    println!(
+        "Copied cached input \"{}\" to \"{}\"",
+        legacy_path.display(),
+        input_path.display()
+    );
+}
+
+fn get_missing_input_paths(config: &Config, year: u32) -> Vec<String> {
+    (1..=25)
+        .map(|day| config.get_input_path(year, day))
+        .filter(|path| !path.is_file())
+        .map(|path| format!("  {}", path.display()))
+        .collect()
 }
 
-pub fn get_input_for_day(year: u32, day: u32) -> String {
-    let session = env::vars()
-        .find(|var| var.0 == "AOC_SESSION")
-        .expect("Please put your session cookie in AOC_SESSION environmental variable")
-        .1;
-    let input_path = format!("input/day{}.txt", day);
+pub fn get_input_for_day(config: &Config, year: u32, day: u32) -> String {
+    let input_path = config.get_input_path(year, day);
+    migrate_legacy_input(year, day, &input_path);
     match fs::read_to_string(&input_path) {
-        Ok(input) => input,
+        Ok(input) if config.raw => input,
+        Ok(input) => verify_cached_input(config, &input_path, input),
+        Err(_) if config.offline => {
+            panic!(
+                "Offline mode is enabled and puzzle input file \"{}\" isn't cached\nCached inputs missing for {}:\n{}",
+                input_path.display(),
+                year,
+                get_missing_input_paths(config, year).join("\n")
+            );
+        }
         Err(_) => {
             println!(
                 "Puzzle input file doesn't exist, fetching it and saving in \"{}\"",
-                input_path
+                input_path.display()
             );
-            let input = fetch_input_for_day(&year, &day, &session);
-            save_input(&input_path, &input);
-            input
+            let client = Client::new(config.get_session());
+            fetch_and_save(&client, year, day, &input_path).unwrap_or_else(|err| panic!("{}", err))
+        }
+    }
+}
+
+fn is_cache_fresh(path: &Path, max_age: Duration) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < max_age)
+}
+
+fn parse_leaderboard(content: &str) -> Leaderboard {
+    serde_json::from_str(content).unwrap_or_else(|_| {
+        panic!("Can't read leaderboard, check your session cookie and whether you are a member of it")
+    })
+}
+
+pub fn get_leaderboard(config: &Config, year: u32, board_id: u64) -> Leaderboard {
+    let cache_path = config.get_leaderboard_path(year, board_id);
+    if is_cache_fresh(&cache_path, LEADERBOARD_REFRESH_INTERVAL) {
+        return parse_leaderboard(&fs::read_to_string(&cache_path).unwrap());
+    }
+    if config.offline {
+        let cached = fs::read_to_string(&cache_path).unwrap_or_else(|_| {
+            panic!(
+                "Offline mode is enabled and leaderboard {} for {} isn't cached in \"{}\"",
+                board_id,
+                year,
+                cache_path.display()
+            )
+        });
+        println!("Offline mode is enabled, showing leaderboard from an outdated cache");
+        return parse_leaderboard(&cached);
+    }
+    let client = Client::new(config.get_session());
+    let url = format!(
+        "{}/{}/leaderboard/private/view/{}.json",
+        client.base_url, year, board_id
+    );
+    let content = client
+        .fetch_url(&url)
+        .unwrap_or_else(|err| panic!("Can't fetch leaderboard: {}", err));
+    let leaderboard = parse_leaderboard(&content);
+    save_to_cache(&cache_path, &content);
+    leaderboard
+}
+
+#[derive(Debug, Default)]
+pub struct FetchReport {
+    pub fetched: Vec<u32>,
+    pub skipped: Vec<(u32, String)>,
+    pub failed: Vec<(u32, String)>,
+}
+
+impl FetchReport {
+    pub fn render(&self) -> String {
+        let mut output = format!("Fetched {} input(s)\n", self.fetched.len());
+        self.fetched.iter().for_each(|day| {
+            output += &format!("  day {}\n", day);
+        });
+        output += &format!("Skipped {} input(s)\n", self.skipped.len());
+        self.skipped.iter().for_each(|(day, reason)| {
+            output += &format!("  day {}: {}\n", day, reason);
+        });
+        output += &format!("Failed to fetch {} input(s)\n", self.failed.len());
+        self.failed.iter().for_each(|(day, reason)| {
+            output += &format!("  day {}: {}\n", day, reason);
+        });
+        output
+    }
+}
+
+fn is_unlocked(year: u32, day: u32) -> bool {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    get_unlock_timestamp(year, day) <= now
+}
+
+pub fn fetch_inputs(config: &Config, year: u32, days: &[u32]) -> FetchReport {
+    if config.offline {
+        panic!("Offline mode is enabled, refusing to fetch puzzle inputs");
+    }
+    let mut report = FetchReport::default();
+    let mut client = None;
+    for (idx, day) in days.iter().enumerate() {
+        let input_path = config.get_input_path(year, *day);
+        migrate_legacy_input(year, *day, &input_path);
+        if input_path.is_file() {
+            report.skipped.push((*day, String::from("already cached")));
+            continue;
+        }
+        if !is_unlocked(year, *day) {
+            report.skipped.push((*day, String::from("not unlocked yet")));
+            continue;
         }
+        if client.is_some() {
+            thread::sleep(BULK_FETCH_DELAY);
+        }
+        let client = client.get_or_insert_with(|| Client::new(config.get_session()));
+        println!("Fetching input for day {} of {}", day, year);
+        match fetch_and_save(client, year, *day, &input_path) {
+            Ok(_) => report.fetched.push(*day),
+            Err(FetchError::Unauthorized) => {
+                // every other request would be refused as well, no point in waiting for them
+                report.failed.push((*day, FetchError::Unauthorized.to_string()));
+                days[idx + 1..].iter().for_each(|day| {
+                    report
+                        .skipped
+                        .push((*day, String::from("session cookie was rejected")));
+                });
+                break;
+            }
+            Err(err) => report.failed.push((*day, err.to_string())),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    // answers a single request with the given status line and body
+    fn serve_once(status: &'static str, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 4096];
+            let _ = stream.read(&mut request).unwrap();
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        format!("http://{}", address)
+    }
+
+    #[test]
+    fn error_page_is_not_cached_test() {
+        let dir = std::env::temp_dir().join(format!("aoc22-network-test-{}", std::process::id()));
+        let input_path = dir.join("day1.txt");
+        let client = Client {
+            base_url: serve_once("500 Internal Server Error", "Something went wrong"),
+            ..Client::new(String::from("session"))
+        };
+
+        let result = fetch_and_save(&client, 2022, 1, &input_path);
+
+        assert!(matches!(result, Err(FetchError::Status(StatusCode::INTERNAL_SERVER_ERROR))));
+        assert!(!input_path.exists());
+
+        let client = Client {
+            base_url: serve_once("200 OK", "1\n2\n"),
+            ..Client::new(String::from("session"))
+        };
+
+        assert_eq!(fetch_and_save(&client, 2022, 1, &input_path).unwrap(), "1\n2\n");
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "1\n2\n");
+        fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub expanded: usize,
+    pub duplicates: usize,
+    pub pruned: usize,
+    pub improvements: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optimum<S> {
+    value: usize,
+    state: S,
+    stats: Stats,
+}
+
+impl<S> Optimum<S> {
+    pub fn get_value(&self) -> usize {
+        self.value
+    }
+
+    pub fn get_state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn get_stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+pub fn maximize<S, I, N, FS, FV, FB>(
+    starts: I,
+    successors: FS,
+    value: FV,
+    upper_bound: FB,
+) -> Option<Optimum<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> N,
+    FV: FnMut(&S) -> usize,
+    FB: FnMut(&S) -> usize,
+{
+    maximize_by_key(starts, successors, value, upper_bound, |state| {
+        state.clone()
+    })
+}
+
+// depth first branch and bound:
+// value is what a state is guaranteed to achieve, upper_bound is what it could achieve at best,
+// branches that can't beat the best value found so far are skipped,
+// states sharing a key are only explored once, keys are compared exactly so nothing gets dropped by a hash collision
+pub fn maximize_by_key<S, K, I, N, FS, FV, FB, FK>(
+    starts: I,
+    mut successors: FS,
+    mut value: FV,
+    mut upper_bound: FB,
+    mut get_key: FK,
+) -> Option<Optimum<S>>
+where
+    S: Clone,
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> N,
+    FV: FnMut(&S) -> usize,
+    FB: FnMut(&S) -> usize,
+    FK: FnMut(&S) -> K,
+{
+    let mut stats = Stats::default();
+    let mut seen = HashSet::new();
+    let mut best: Option<(usize, S)> = None;
+    let mut stack = vec![];
+    for start in starts {
+        if seen.insert(get_key(&start)) {
+            stack.push(start);
+        } else {
+            stats.duplicates += 1;
+        }
+    }
+    while let Some(state) = stack.pop() {
+        let state_value = value(&state);
+        if best
+            .as_ref()
+            .is_none_or(|(best_value, _)| state_value > *best_value)
+        {
+            best = Some((state_value, state.clone()));
+            stats.improvements += 1;
+        }
+        let best_value = best.as_ref().unwrap().0;
+        if upper_bound(&state) <= best_value {
+            stats.pruned += 1;
+            continue;
+        }
+        stats.expanded += 1;
+        for next in successors(&state) {
+            if seen.insert(get_key(&next)) {
+                stack.push(next);
+            } else {
+                stats.duplicates += 1;
+            }
+        }
+    }
+    best.map(|(value, state)| Optimum {
+        value,
+        state,
+        stats,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (index of the next item to consider, weight, value)
+    type KnapsackState = (usize, usize, usize);
+
+    fn get_items() -> Vec<(usize, usize)> {
+        vec![(12, 4), (2, 2), (1, 1), (1, 2), (4, 10)]
+    }
+
+    fn solve_knapsack(capacity: usize, use_bound: bool) -> Optimum<KnapsackState> {
+        let items = get_items();
+        maximize(
+            [(0, 0, 0)],
+            |&(i, weight, value): &KnapsackState| {
+                let mut next = vec![];
+                if i < items.len() {
+                    next.push((i + 1, weight, value));
+                    if weight + items[i].0 <= capacity {
+                        next.push((i + 1, weight + items[i].0, value + items[i].1));
+                    }
+                }
+                next
+            },
+            |state| state.2,
+            |&(i, _, value)| {
+                if use_bound {
+                    value + items[i..].iter().map(|item| item.1).sum::<usize>()
+                } else {
+                    usize::MAX
+                }
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn knapsack_test() {
+        let optimum = solve_knapsack(15, true);
+        let exhaustive = solve_knapsack(15, false);
+
+        assert_eq!(optimum.get_value(), 15);
+        assert_eq!(optimum.get_state().1, 8);
+        assert_eq!(exhaustive.get_value(), 15);
+        assert!(optimum.get_stats().pruned > 0);
+        assert!(optimum.get_stats().expanded < exhaustive.get_stats().expanded);
+    }
+
+    #[test]
+    fn dedupe_test() {
+        // every number below 20 can be reached in many ways, but gets expanded only once
+        let optimum = maximize_by_key(
+            [0usize],
+            |n| [n + 1, n + 2].into_iter().filter(|n| *n < 20),
+            |n| *n,
+            |_| usize::MAX,
+            |n| *n,
+        )
+        .unwrap();
+
+        assert_eq!(optimum.get_value(), 19);
+        assert_eq!(optimum.get_stats().expanded, 20);
+        assert_eq!(optimum.get_stats().duplicates, 18);
+    }
+}
@@ -0,0 +1,363 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::input;
+
+// lines and columns start at 1 and are relative to the text that was handed to the parser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: String) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message,
+        }
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    // moves the position into a bigger text, the text that was parsed started at the given line and column
+    // (columns are only shifted on the first line, as the following ones start at the beginning of a line anyway)
+    pub fn offset(mut self, line: usize, column: usize) -> ParseError {
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+fn get_column(line: &str, byte_offset: usize) -> usize {
+    line[..byte_offset].chars().count() + 1
+}
+
+// groups of lines separated by blank ones, together with the line each of them starts at
+fn get_paragraphs_with_lines(input: &str) -> Vec<(usize, &str)> {
+    let mut paragraphs = vec![];
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        let end = offset + line.trim_end_matches(['\n', '\r']).len();
+        if line.trim().is_empty() {
+            if let Some((first_line, start, end)) = current.take() {
+                paragraphs.push((first_line, &input[start..end]));
+            }
+        } else {
+            current = match current {
+                Some((first_line, start, _)) => Some((first_line, start, end)),
+                None => Some((i + 1, offset, end)),
+            };
+        }
+        offset += line.len();
+    }
+    if let Some((first_line, start, end)) = current {
+        paragraphs.push((first_line, &input[start..end]));
+    }
+    paragraphs
+}
+
+pub fn paragraphs(input: &str) -> Vec<&str> {
+    get_paragraphs_with_lines(input)
+        .into_iter()
+        .map(|(_, paragraph)| paragraph)
+        .collect()
+}
+
+pub fn parse_paragraphs<T, F>(input: &str, mut parse_paragraph: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    get_paragraphs_with_lines(input)
+        .into_iter()
+        .map(|(line, paragraph)| parse_paragraph(paragraph).map_err(|e| e.offset(line, 1)))
+        .collect()
+}
+
+fn parse_numbered_lines<S, I, T, F>(lines: I, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    S: AsRef<str>,
+    I: Iterator<Item = S>,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    lines
+        .enumerate()
+        .filter(|(_, line)| !line.as_ref().trim().is_empty())
+        .map(|(i, line)| {
+            let line = line.as_ref();
+            let indent = line.len() - line.trim_start().len();
+            parse_line(line.trim()).map_err(|e| e.offset(i + 1, get_column(line, indent)))
+        })
+        .collect()
+}
+
+// blank lines are skipped and the rest gets trimmed before parsing, so indented test inputs work too
+pub fn parse_lines<T, F>(input: &str, parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    parse_numbered_lines(input.lines(), parse_line)
+}
+
+// same as parse_lines, but without having the whole input in memory
+pub fn parse_lines_from_reader<R, T, F>(reader: R, parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    parse_numbered_lines(input::read_lines(reader), parse_line)
+}
+
+// "Starting items: 79, 98" -> ("Starting items", "79, 98")
+pub fn key_value(line: &str) -> Result<(&str, &str), ParseError> {
+    match line.split_once(':') {
+        Some((key, value)) => Ok((key.trim(), value.trim())),
+        None => Err(ParseError::new(
+            1,
+            get_column(line, line.len()),
+            String::from("expected `:`"),
+        )),
+    }
+}
+
+pub fn split_trimmed<'a>(line: &'a str, separator: &str) -> Vec<&'a str> {
+    line.split(separator).map(|part| part.trim()).collect()
+}
+
+// "498,4 -> 498,6 -> 496,6" -> ["498,4", "498,6", "496,6"]
+pub fn split_arrows(line: &str) -> Vec<&str> {
+    split_trimmed(line, "->")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'a> {
+    values: Vec<(usize, &'a str)>,
+}
+
+impl<'a> Captures<'a> {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get_str(&self, i: usize) -> &'a str {
+        self.values[i].1
+    }
+
+    pub fn get<T: FromStr>(&self, i: usize) -> Result<T, ParseError> {
+        let (column, value) = self.values[i];
+        value.parse().map_err(|_| {
+            ParseError::new(
+                1,
+                column,
+                format!("can't parse `{}` as {}", value, std::any::type_name::<T>()),
+            )
+        })
+    }
+}
+
+// literal text with {} placeholders, e.g. "Sensor at x={}, y={}",
+// each placeholder captures everything up to the first occurrence of the text following it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    literals: Vec<String>,
+}
+
+impl Pattern {
+    pub fn new(template: &str) -> Pattern {
+        let literals = template
+            .split("{}")
+            .map(String::from)
+            .collect::<Vec<String>>();
+        // a template without any placeholders is a single literal that has to match the whole line
+        let separators = literals.get(1..literals.len() - 1).unwrap_or_default();
+        if separators.iter().any(|literal| literal.is_empty()) {
+            panic!("Placeholders in a pattern have to be separated with some text");
+        }
+        Pattern { literals }
+    }
+
+    fn expect_literal(line: &str, pos: usize, literal: &str) -> Result<usize, ParseError> {
+        if line[pos..].starts_with(literal) {
+            return Ok(pos + literal.len());
+        }
+        let matching_len = line[pos..]
+            .char_indices()
+            .zip(literal.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(line.len() - pos, |((i, _), _)| i);
+        Err(ParseError::new(
+            1,
+            get_column(line, pos + matching_len),
+            format!("expected `{}`", &literal[matching_len..]),
+        ))
+    }
+
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Captures<'a>, ParseError> {
+        let mut pos = Pattern::expect_literal(line, 0, &self.literals[0])?;
+        let mut values = vec![];
+        for literal in self.literals[1..].iter() {
+            let end = if literal.is_empty() {
+                line.len()
+            } else {
+                match line[pos..].find(literal.as_str()) {
+                    Some(i) => pos + i,
+                    None => {
+                        return Err(ParseError::new(
+                            1,
+                            get_column(line, pos),
+                            format!("expected a value followed by `{}`", literal),
+                        ))
+                    }
+                }
+            };
+            if end == pos {
+                return Err(ParseError::new(
+                    1,
+                    get_column(line, pos),
+                    String::from("expected a value"),
+                ));
+            }
+            values.push((get_column(line, pos), &line[pos..end]));
+            pos = end + literal.len();
+        }
+        if pos != line.len() {
+            return Err(ParseError::new(
+                1,
+                get_column(line, pos),
+                format!("unexpected `{}`", &line[pos..]),
+            ));
+        }
+        Ok(Captures { values })
+    }
+
+    // for patterns where every placeholder holds the same type
+    pub fn extract<T: FromStr>(&self, line: &str) -> Result<Vec<T>, ParseError> {
+        let captures = self.captures(line)?;
+        (0..captures.len()).map(|i| captures.get(i)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> &'static str {
+        "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+        Sensor at x=9, y=16: closest beacon is at x=10, y=16
+
+        Sensor at x=13, y=2: closest beacon at x=15, y=3"
+    }
+
+    #[test]
+    fn paragraphs_test() {
+        let input = "\n1\n2\n\n3\n  \n\n4\n5\n";
+
+        assert_eq!(paragraphs(input), vec!["1\n2", "3", "4\n5"]);
+        assert_eq!(paragraphs("1\r\n\r\n2\r\n"), vec!["1", "2"]);
+        assert_eq!(paragraphs("\n \n"), Vec::<&str>::new());
+
+        let error = parse_paragraphs(input, |paragraph| {
+            parse_lines(paragraph, |line| {
+                line.parse::<usize>()
+                    .map_err(|_| ParseError::new(1, 1, String::from("not a number")))
+                    .and_then(|n| match n {
+                        5 => Err(ParseError::new(1, 1, String::from("five"))),
+                        n => Ok(n),
+                    })
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "9:1: five");
+        assert_eq!(
+            parse_lines_from_reader("1\n\n  x".as_bytes(), |line| line
+                .parse::<usize>()
+                .map_err(|_| ParseError::new(1, 1, String::from("not a number"))))
+            .unwrap_err()
+            .to_string(),
+            "3:3: not a number"
+        );
+    }
+
+    #[test]
+    fn pattern_test() {
+        let pattern = Pattern::new("Sensor at x={}, y={}: closest beacon is at x={}, y={}");
+
+        let captures = pattern
+            .captures("Sensor at x=2, y=18: closest beacon is at x=-2, y=15")
+            .unwrap();
+
+        assert_eq!(captures.len(), 4);
+        assert_eq!(captures.get_str(2), "-2");
+        assert_eq!(captures.get::<isize>(2), Ok(-2));
+        assert_eq!(
+            captures.get::<usize>(2).unwrap_err(),
+            ParseError::new(1, 45, String::from("can't parse `-2` as usize"))
+        );
+
+        let error = parse_lines(get_input(), |line| pattern.extract::<isize>(line)).unwrap_err();
+
+        assert_eq!(
+            (error.get_line(), error.get_column(), error.get_message()),
+            (
+                4,
+                27,
+                "expected a value followed by `: closest beacon is at x=`"
+            )
+        );
+
+        let pattern = Pattern::new("{} -> {}");
+
+        assert_eq!(pattern.extract::<u8>("1 -> 2"), Ok(vec![1, 2]));
+        assert_eq!(pattern.extract::<u8>("1 -> ").unwrap_err().get_column(), 6);
+        assert_eq!(pattern.extract::<u8>("1 + 2").unwrap_err().get_column(), 1);
+
+        let pattern = Pattern::new("noop");
+
+        assert_eq!(pattern.captures("noop").unwrap().len(), 0);
+        assert_eq!(pattern.captures("noop 1").unwrap_err().get_column(), 5);
+    }
+
+    #[test]
+    fn splitters_test() {
+        assert_eq!(
+            key_value("  Starting items: 79, 98"),
+            Ok(("Starting items", "79, 98"))
+        );
+        assert_eq!(key_value("Monkey 0").unwrap_err().get_column(), 9);
+        assert_eq!(
+            split_arrows("498,4 -> 498,6 -> 496,6"),
+            vec!["498,4", "498,6", "496,6"]
+        );
+        assert_eq!(split_trimmed("a, b,c", ","), vec!["a", "b", "c"]);
+    }
+}
@@ -0,0 +1,194 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use super::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Coordinates {
+    x: isize,
+    y: isize,
+}
+
+impl Coordinates {
+    pub fn new(x: isize, y: isize) -> Coordinates {
+        Coordinates { x, y }
+    }
+
+    pub fn get_x(&self) -> isize {
+        self.x
+    }
+
+    pub fn get_y(&self) -> isize {
+        self.y
+    }
+
+    pub fn from_yx((y, x): (isize, isize)) -> Coordinates {
+        Coordinates { x, y }
+    }
+
+    pub fn to_yx(self) -> (isize, isize) {
+        (self.y, self.x)
+    }
+
+    pub fn manhattan_distance(&self, other: &Coordinates) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Coordinates) -> usize {
+        std::cmp::max(self.x.abs_diff(other.x), self.y.abs_diff(other.y))
+    }
+
+    pub fn euclidean_distance(&self, other: &Coordinates) -> f64 {
+        let dx = self.x.abs_diff(other.x) as f64;
+        let dy = self.y.abs_diff(other.y) as f64;
+        dx.hypot(dy)
+    }
+
+    pub fn neighbors4(&self) -> [Coordinates; 4] {
+        Direction::get_basic_directions().map(|dir| *self + dir)
+    }
+
+    pub fn neighbors8(&self) -> [Coordinates; 8] {
+        Direction::get_all_directions().map(|dir| *self + dir)
+    }
+}
+
+// tuples are (y, x) like everywhere else in plane, same as from_yx and to_yx
+impl From<(isize, isize)> for Coordinates {
+    fn from(pos: (isize, isize)) -> Coordinates {
+        Coordinates::from_yx(pos)
+    }
+}
+
+impl From<Coordinates> for (isize, isize) {
+    fn from(coords: Coordinates) -> (isize, isize) {
+        coords.to_yx()
+    }
+}
+
+impl From<Direction> for Coordinates {
+    fn from(dir: Direction) -> Coordinates {
+        Coordinates::from_yx(dir.get_offset())
+    }
+}
+
+impl Add for Coordinates {
+    type Output = Coordinates;
+
+    fn add(self, other: Coordinates) -> Coordinates {
+        Coordinates::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Coordinates {
+    type Output = Coordinates;
+
+    fn sub(self, other: Coordinates) -> Coordinates {
+        Coordinates::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add<Direction> for Coordinates {
+    type Output = Coordinates;
+
+    fn add(self, dir: Direction) -> Coordinates {
+        self + Coordinates::from(dir)
+    }
+}
+
+impl Sub<Direction> for Coordinates {
+    type Output = Coordinates;
+
+    fn sub(self, dir: Direction) -> Coordinates {
+        self - Coordinates::from(dir)
+    }
+}
+
+impl AddAssign for Coordinates {
+    fn add_assign(&mut self, other: Coordinates) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Coordinates {
+    fn sub_assign(&mut self, other: Coordinates) {
+        *self = *self - other;
+    }
+}
+
+impl AddAssign<Direction> for Coordinates {
+    fn add_assign(&mut self, dir: Direction) {
+        *self = *self + dir;
+    }
+}
+
+impl SubAssign<Direction> for Coordinates {
+    fn sub_assign(&mut self, dir: Direction) {
+        *self = *self - dir;
+    }
+}
+
+impl Mul<isize> for Coordinates {
+    type Output = Coordinates;
+
+    fn mul(self, factor: isize) -> Coordinates {
+        Coordinates::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Mul<isize> for Direction {
+    type Output = Coordinates;
+
+    fn mul(self, factor: isize) -> Coordinates {
+        Coordinates::from(self) * factor
+    }
+}
+
+impl Neg for Coordinates {
+    type Output = Coordinates;
+
+    fn neg(self) -> Coordinates {
+        Coordinates::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_test() {
+        let a = Coordinates::new(2, 3);
+        let b = Coordinates::new(-1, 4);
+
+        assert_eq!(a + b, Coordinates::new(1, 7));
+        assert_eq!(a - b, Coordinates::new(3, -1));
+        assert_eq!(a * 3, Coordinates::new(6, 9));
+        assert_eq!(-a, Coordinates::new(-2, -3));
+        assert_eq!(a + Direction::Up, Coordinates::new(2, 2));
+        assert_eq!(a - Direction::DownRight, Coordinates::new(1, 2));
+        assert_eq!(a + Direction::Left * 2, Coordinates::new(0, 3));
+        assert_eq!(Coordinates::from_yx(a.to_yx()), a);
+        assert_eq!(Coordinates::from((3, 2)), a);
+        assert_eq!(<(isize, isize)>::from(a), (3, 2));
+        assert_eq!(a.to_yx(), (3, 2));
+    }
+
+    #[test]
+    fn distance_test() {
+        let a = Coordinates::new(0, 0);
+        let b = Coordinates::new(3, -4);
+
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.euclidean_distance(&b), 5.0);
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let a = Coordinates::new(5, 5);
+
+        assert!(a.neighbors4().iter().all(|n| a.manhattan_distance(n) == 1));
+        assert!(a.neighbors8().iter().all(|n| a.chebyshev_distance(n) == 1));
+        assert_eq!(a.neighbors4()[0], Coordinates::new(5, 4));
+    }
+}
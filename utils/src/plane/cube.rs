@@ -0,0 +1,327 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::Direction;
+
+type Vector = [isize; 3];
+
+fn neg(a: Vector) -> Vector {
+    [-a[0], -a[1], -a[2]]
+}
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: Vector, factor: isize) -> Vector {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+fn dot(a: Vector, b: Vector) -> isize {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    origin: (usize, usize),
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    fn get_vector(&self, dir: &Direction) -> Vector {
+        match dir {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => neg(self.right),
+            Direction::Up => neg(self.down),
+            _ => panic!("Only basic directions can be used on a cube"),
+        }
+    }
+
+    fn get_direction(&self, vector: Vector) -> Direction {
+        *Direction::get_basic_directions()
+            .iter()
+            .find(|dir| self.get_vector(dir) == vector)
+            .expect("Vector doesn't lie on the face")
+    }
+
+    fn get_neighbour(&self, dir: &Direction) -> Face {
+        let vector = self.get_vector(dir);
+        let (normal, back) = (vector, neg(self.normal));
+        match dir {
+            Direction::Right | Direction::Left => Face {
+                right: if dir == &Direction::Right { back } else { self.normal },
+                normal,
+                ..*self
+            },
+            _ => Face {
+                down: if dir == &Direction::Down { back } else { self.normal },
+                normal,
+                ..*self
+            },
+        }
+    }
+}
+
+// every cell is mapped onto the cube surface using doubled coordinates,
+// so cell centers of a face of size n span from -(n - 1) to n - 1 and the face itself lies at n
+#[derive(Debug)]
+pub struct CubeNet {
+    face_size: usize,
+    faces: Vec<Face>,
+    face_lookup: HashMap<(usize, usize), usize>,
+}
+
+impl CubeNet {
+    pub fn new<F: Fn((usize, usize)) -> bool>(width: usize, height: usize, is_void: F) -> CubeNet {
+        let area = (0..height)
+            .flat_map(|i| (0..width).map(move |j| (i, j)))
+            .filter(|pos| !is_void(*pos))
+            .count();
+        let face_size = (1..=width.max(height))
+            .find(|size| 6 * size * size >= area)
+            .filter(|size| 6 * size * size == area)
+            .expect("Map area doesn't match any cube");
+        let mut face_origins = vec![];
+        for i in (0..height).step_by(face_size) {
+            for j in (0..width).step_by(face_size) {
+                let is_face = !is_void((i, j));
+                let is_complete = i + face_size <= height
+                    && j + face_size <= width
+                    && (i..i + face_size)
+                        .all(|y| (j..j + face_size).all(|x| is_void((y, x)) != is_face));
+                if !is_complete {
+                    panic!("Map isn't made of full {}x{} faces", face_size, face_size);
+                }
+                if is_face {
+                    face_origins.push((i / face_size, j / face_size));
+                }
+            }
+        }
+        if face_origins.len() != 6 {
+            panic!("Cube net must have exactly 6 faces");
+        }
+
+        let mut faces: HashMap<(usize, usize), Face> = HashMap::new();
+        let first_face = Face {
+            origin: face_origins[0],
+            normal: [0, 0, -1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        };
+        faces.insert(first_face.origin, first_face);
+        let mut queue = VecDeque::from([first_face]);
+        while let Some(face) = queue.pop_front() {
+            let (y, x) = face.origin;
+            Direction::get_basic_directions().iter().for_each(|dir| {
+                let (dy, dx) = dir.get_offset();
+                let origin = ((y as isize + dy) as usize, (x as isize + dx) as usize);
+                if face_origins.contains(&origin) && !faces.contains_key(&origin) {
+                    let neighbour = Face {
+                        origin,
+                        ..face.get_neighbour(dir)
+                    };
+                    faces.insert(origin, neighbour);
+                    queue.push_back(neighbour);
+                }
+            });
+        }
+        if faces.len() != 6 {
+            panic!("Cube net faces must be connected");
+        }
+        let faces = face_origins
+            .iter()
+            .map(|origin| faces[origin])
+            .collect::<Vec<Face>>();
+        if (0..6).any(|i| (i + 1..6).any(|j| faces[i].normal == faces[j].normal)) {
+            panic!("Cube net faces overlap when folded");
+        }
+        let face_lookup = faces
+            .iter()
+            .enumerate()
+            .map(|(i, face)| (face.origin, i))
+            .collect();
+        CubeNet {
+            face_size,
+            faces,
+            face_lookup,
+        }
+    }
+
+    pub fn get_face_size(&self) -> usize {
+        self.face_size
+    }
+
+    pub fn get_face(&self, (i, j): (usize, usize)) -> Option<usize> {
+        self.face_lookup
+            .get(&(i / self.face_size, j / self.face_size))
+            .copied()
+    }
+
+    pub fn get_face_origins(&self) -> Vec<(usize, usize)> {
+        self.faces
+            .iter()
+            .map(|face| (face.origin.0 * self.face_size, face.origin.1 * self.face_size))
+            .collect()
+    }
+
+    fn to_space(&self, face: &Face, (i, j): (usize, usize)) -> Vector {
+        let n = self.face_size as isize;
+        let local_i = (i - face.origin.0 * self.face_size) as isize;
+        let local_j = (j - face.origin.1 * self.face_size) as isize;
+        add(
+            scale(face.normal, n),
+            add(
+                scale(face.right, 2 * local_j - (n - 1)),
+                scale(face.down, 2 * local_i - (n - 1)),
+            ),
+        )
+    }
+
+    fn to_map(&self, face: &Face, point: Vector) -> (usize, usize) {
+        let n = self.face_size as isize;
+        let local_j = (dot(point, face.right) + n - 1) / 2;
+        let local_i = (dot(point, face.down) + n - 1) / 2;
+        (
+            face.origin.0 * self.face_size + local_i as usize,
+            face.origin.1 * self.face_size + local_j as usize,
+        )
+    }
+
+    pub fn step(&self, pos: (usize, usize), dir: &Direction) -> ((usize, usize), Direction) {
+        let face = &self.faces[self.get_face(pos).expect("Position isn't on the cube")];
+        let travel = face.get_vector(dir);
+        let point = self.to_space(face, pos);
+        if dot(point, travel) < self.face_size as isize - 1 {
+            let (dy, dx) = dir.get_offset();
+            return (
+                ((pos.0 as isize + dy) as usize, (pos.1 as isize + dx) as usize),
+                *dir,
+            );
+        }
+        let new_face = self
+            .faces
+            .iter()
+            .find(|other| other.normal == travel)
+            .unwrap();
+        let new_point = add(point, add(travel, neg(face.normal)));
+        (
+            self.to_map(new_face, new_point),
+            new_face.get_direction(neg(face.normal)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the 11 distinct cube nets, each character stands for a single face
+    fn get_nets() -> Vec<&'static str> {
+        vec![
+            "#...\n####\n#...",
+            "#...\n####\n.#..",
+            "#...\n####\n..#.",
+            "#...\n####\n...#",
+            ".#..\n####\n.#..",
+            ".#..\n####\n..#.",
+            "##..\n.###\n.#..",
+            "##..\n.###\n..#.",
+            "##..\n.###\n...#",
+            "##..\n.##.\n..##",
+            "###..\n..###",
+        ]
+    }
+
+    fn build_map(net: &str, face_size: usize, transpose: bool) -> Vec<Vec<bool>> {
+        let mut faces = net
+            .lines()
+            .map(|line| line.chars().map(|c| c == '#').collect::<Vec<bool>>())
+            .collect::<Vec<Vec<bool>>>();
+        if transpose {
+            faces = (0..faces[0].len())
+                .map(|j| faces.iter().map(|row| row[j]).collect())
+                .collect();
+        }
+        (0..faces.len() * face_size)
+            .map(|i| {
+                (0..faces[0].len() * face_size)
+                    .map(|j| faces[i / face_size][j / face_size])
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn for_each_net<F: Fn(&Vec<Vec<bool>>, &CubeNet)>(check: F) {
+        for net in get_nets() {
+            for face_size in [1, 2, 3, 4] {
+                for transpose in [false, true] {
+                    let map = build_map(net, face_size, transpose);
+                    let cube = CubeNet::new(map[0].len(), map.len(), |(i, j)| !map[i][j]);
+                    check(&map, &cube);
+                }
+            }
+        }
+    }
+
+    fn get_cells(map: &[Vec<bool>]) -> Vec<(usize, usize)> {
+        (0..map.len())
+            .flat_map(|i| (0..map[0].len()).map(move |j| (i, j)))
+            .filter(|(i, j)| map[*i][*j])
+            .collect()
+    }
+
+    #[test]
+    fn face_detection_test() {
+        for_each_net(|map, cube| {
+            assert_eq!(cube.get_face_origins().len(), 6);
+            assert!(get_cells(map).iter().all(|pos| cube.get_face(*pos).is_some()));
+        });
+    }
+
+    #[test]
+    fn step_back_test() {
+        for_each_net(|map, cube| {
+            for pos in get_cells(map) {
+                for dir in Direction::get_basic_directions() {
+                    let (new_pos, new_dir) = cube.step(pos, dir);
+                    assert!(map[new_pos.0][new_pos.1]);
+
+                    let (old_pos, old_dir) = cube.step(new_pos, &new_dir.opposite());
+
+                    assert_eq!((old_pos, old_dir), (pos, dir.opposite()));
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn great_circle_test() {
+        for_each_net(|map, cube| {
+            for pos in get_cells(map) {
+                for dir in Direction::get_basic_directions() {
+                    let (mut cur_pos, mut cur_dir) = (pos, *dir);
+                    let mut visited_faces = vec![];
+                    for _ in 0..4 * cube.get_face_size() {
+                        (cur_pos, cur_dir) = cube.step(cur_pos, &cur_dir);
+                        let face = cube.get_face(cur_pos).unwrap();
+                        if !visited_faces.contains(&face) {
+                            visited_faces.push(face);
+                        }
+                    }
+
+                    assert_eq!((cur_pos, cur_dir), (pos, *dir));
+                    assert_eq!(visited_faces.len(), 4);
+                }
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_net_test() {
+        let map = build_map("####\n####", 2, false);
+        CubeNet::new(map[0].len(), map.len(), |(i, j)| !map[i][j]);
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt;
+
+use super::{Boundary, Coordinates, Direction};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        if cells.len() != width * height {
+            panic!(
+                "Grid of size {}x{} can't hold {} cells",
+                width,
+                height,
+                cells.len()
+            );
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            panic!("All grid rows must have the same length");
+        }
+        Grid::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    pub fn parse<F: FnMut(char) -> T>(input: &str, mut map_char: F) -> Grid<T> {
+        let rows = input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(&mut map_char).collect::<Vec<T>>())
+            .collect::<Vec<Vec<T>>>();
+        Grid::from_rows(rows)
+    }
+
+    pub fn parse_ragged<F: FnMut(char) -> T>(input: &str, fill: char, mut map_char: F) -> Grid<T> {
+        let width = input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let rows = input
+            .lines()
+            .map(|line| {
+                let padding = width - line.chars().count();
+                line.chars()
+                    .chain(std::iter::repeat_n(fill, padding))
+                    .map(&mut map_char)
+                    .collect::<Vec<T>>()
+            })
+            .collect::<Vec<Vec<T>>>();
+        Grid::from_rows(rows)
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_boundary(&self) -> Boundary {
+        Boundary::new_array_boundary(self.width, self.height)
+    }
+
+    pub fn contains(&self, coords: &Coordinates) -> bool {
+        coords.get_x() >= 0
+            && coords.get_y() >= 0
+            && (coords.get_x() as usize) < self.width
+            && (coords.get_y() as usize) < self.height
+    }
+
+    fn get_index(&self, coords: &Coordinates) -> Option<usize> {
+        if self.contains(coords) {
+            Some(coords.get_y() as usize * self.width + coords.get_x() as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, coords: &Coordinates) -> Option<&T> {
+        self.get_index(coords).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coords: &Coordinates) -> Option<&mut T> {
+        self.get_index(coords).map(|i| &mut self.cells[i])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinates, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, cell)| {
+            (
+                Coordinates::new((i % width) as isize, (i / width) as isize),
+                cell,
+            )
+        })
+    }
+
+    pub fn find<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<Coordinates> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coords, _)| coords)
+    }
+
+    pub fn get_neighbours<'a>(
+        &'a self,
+        coords: &Coordinates,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Direction, Coordinates, &'a T)> + 'a {
+        let boundary = self.get_boundary();
+        let pos = (coords.get_y(), coords.get_x());
+        let is_inside = self.contains(coords);
+        directions.iter().filter_map(move |dir| {
+            if !is_inside {
+                return None;
+            }
+            let (y, x) = boundary.move_iterator(pos, dir)?;
+            let neighbour = Coordinates::new(x, y);
+            self.get(&neighbour).map(|cell| (*dir, neighbour, cell))
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells.iter().skip(x).step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, map_cell: F) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(map_cell).collect())
+    }
+
+    pub fn render<F: Fn(&T) -> char>(&self, render_cell: F) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&render_cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new_filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    fn remap<F: Fn(usize, usize) -> (usize, usize)>(
+        &self,
+        width: usize,
+        height: usize,
+        get_source: F,
+    ) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (source_x, source_y) = get_source(x, y);
+                self.cells[source_y * self.width + source_x].clone()
+            })
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate(&self, dir: &Direction) -> Grid<T> {
+        match dir {
+            Direction::Right => self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x)),
+            Direction::Left => self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x)),
+            _ => self.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_input() -> &'static str {
+        "abc
+        def"
+    }
+
+    #[test]
+    fn parsing_test() {
+        let grid = Grid::parse(get_input(), |c| c);
+
+        assert_eq!(grid.get_width(), 3);
+        assert_eq!(grid.get_height(), 2);
+        assert_eq!(grid.get(&Coordinates::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(&Coordinates::new(3, 1)), None);
+        assert_eq!(grid.get(&Coordinates::new(-1, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn ragged_parsing_test() {
+        let grid = Grid::parse_ragged("  .#\n.", ' ', |c| c);
+
+        assert_eq!(grid.to_string(), "  .#\n.   ");
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let grid = Grid::parse(get_input(), |c| c);
+
+        let neighbours = grid
+            .get_neighbours(&Coordinates::new(0, 0), Direction::get_basic_directions())
+            .map(|(dir, _, c)| (dir, *c))
+            .collect::<Vec<(Direction, char)>>();
+
+        assert_eq!(
+            neighbours,
+            vec![(Direction::Right, 'b'), (Direction::Down, 'd')]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns_test() {
+        let grid = Grid::parse(get_input(), |c| c);
+
+        let rows = grid
+            .rows()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<String>>();
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<String>>();
+
+        assert_eq!(rows, vec!["abc", "def"]);
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.column(1).unwrap().collect::<String>(), "be");
+    }
+
+    #[test]
+    fn transpose_and_rotate_test() {
+        let grid = Grid::parse(get_input(), |c| c);
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate(&Direction::Right).to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate(&Direction::Left).to_string(), "cf\nbe\nad");
+        assert_eq!(
+            grid.rotate(&Direction::Right).rotate(&Direction::Left),
+            grid
+        );
+    }
+}
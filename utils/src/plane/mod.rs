@@ -0,0 +1,375 @@
+mod coordinates;
+pub mod cube;
+mod grid;
+mod sparse_grid;
+pub mod topology;
+
+pub use coordinates::Coordinates;
+pub use grid::Grid;
+pub use sparse_grid::SparseGrid;
+use topology::{Topology, Torus};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction {
+    None,
+    Up,
+    Right,
+    Down,
+    Left,
+    UpRight,
+    DownRight,
+    DownLeft,
+    UpLeft,
+}
+
+static CLOCKWISE_DIRECTIONS: [Direction; 8] = [
+    Direction::Up,
+    Direction::UpRight,
+    Direction::Right,
+    Direction::DownRight,
+    Direction::Down,
+    Direction::DownLeft,
+    Direction::Left,
+    Direction::UpLeft,
+];
+
+impl Direction {
+    pub fn get_basic_directions() -> &'static [Direction; 4] {
+        static DIRECTIONS: [Direction; 4] = [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ];
+        &DIRECTIONS
+    }
+
+    pub fn get_diagonal_directions() -> &'static [Direction; 4] {
+        static DIRECTIONS: [Direction; 4] = [
+            Direction::UpRight,
+            Direction::DownRight,
+            Direction::DownLeft,
+            Direction::UpLeft,
+        ];
+        &DIRECTIONS
+    }
+
+    pub fn get_all_directions() -> &'static [Direction; 8] {
+        &CLOCKWISE_DIRECTIONS
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        Direction::get_diagonal_directions().contains(self)
+    }
+
+    pub fn get_offset(&self) -> (isize, isize) {
+        match self {
+            Direction::None => (0, 0),
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::UpRight => (-1, 1),
+            Direction::DownRight => (1, 1),
+            Direction::DownLeft => (1, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn from_offset((dy, dx): (isize, isize)) -> Direction {
+        match (dy.signum(), dx.signum()) {
+            (-1, 0) => Direction::Up,
+            (0, 1) => Direction::Right,
+            (1, 0) => Direction::Down,
+            (0, -1) => Direction::Left,
+            (-1, 1) => Direction::UpRight,
+            (1, 1) => Direction::DownRight,
+            (1, -1) => Direction::DownLeft,
+            (-1, -1) => Direction::UpLeft,
+            _ => Direction::None,
+        }
+    }
+
+    fn rotate_by_eighths(&self, eighths: isize) -> Direction {
+        match CLOCKWISE_DIRECTIONS.iter().position(|dir| dir == self) {
+            Some(i) => CLOCKWISE_DIRECTIONS[(i as isize + eighths).rem_euclid(8) as usize],
+            None => Direction::None,
+        }
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.rotate_by_eighths(4)
+    }
+
+    pub fn rotate(&self, dir: &Direction) -> Direction {
+        match dir {
+            Direction::Right => self.rotate_by_eighths(2),
+            Direction::Left => self.rotate_by_eighths(-2),
+            _ => *self,
+        }
+    }
+
+    pub fn rotate_45(&self, dir: &Direction) -> Direction {
+        match dir {
+            Direction::Right => self.rotate_by_eighths(1),
+            Direction::Left => self.rotate_by_eighths(-1),
+            _ => *self,
+        }
+    }
+
+    pub fn rotate_180(&self) -> Direction {
+        self.opposite()
+    }
+}
+
+pub struct Boundary {
+    low_x_boundary: isize,
+    low_y_boundary: isize,
+    high_x_boundary: isize,
+    high_y_boundary: isize,
+}
+
+impl Boundary {
+    pub fn new(
+        low_x_boundary: isize,
+        low_y_boundary: isize,
+        high_x_boundary: isize,
+        high_y_boundary: isize,
+    ) -> Boundary {
+        return Boundary {
+            low_x_boundary,
+            low_y_boundary,
+            high_x_boundary,
+            high_y_boundary,
+        };
+    }
+
+    pub fn new_array_boundary(high_x_boundary: usize, high_y_boundary: usize) -> Boundary {
+        return Boundary {
+            low_x_boundary: 0,
+            low_y_boundary: 0,
+            high_x_boundary: high_x_boundary as isize,
+            high_y_boundary: high_y_boundary as isize,
+        };
+    }
+
+    pub fn new_infinite_boundary() -> Boundary {
+        return Boundary {
+            low_x_boundary: isize::MIN,
+            low_y_boundary: isize::MIN,
+            high_x_boundary: isize::MAX,
+            high_y_boundary: isize::MAX,
+        };
+    }
+
+    pub fn move_array_iterator(
+        &self,
+        (i, j): (usize, usize),
+        dir: &Direction,
+    ) -> Option<(usize, usize)> {
+        if let Some((new_i, new_j)) = self.move_iterator((i as isize, j as isize), dir) {
+            return Some((new_i as usize, new_j as usize));
+        }
+        return None;
+    }
+
+    pub fn move_iterator(&self, (i, j): (isize, isize), dir: &Direction) -> Option<(isize, isize)> {
+        match dir {
+            Direction::UpRight => self
+                .move_iterator((i, j), &Direction::Up)
+                .and_then(|pos| self.move_iterator(pos, &Direction::Right)),
+            Direction::DownRight => self
+                .move_iterator((i, j), &Direction::Down)
+                .and_then(|pos| self.move_iterator(pos, &Direction::Right)),
+            Direction::DownLeft => self
+                .move_iterator((i, j), &Direction::Down)
+                .and_then(|pos| self.move_iterator(pos, &Direction::Left)),
+            Direction::UpLeft => self
+                .move_iterator((i, j), &Direction::Up)
+                .and_then(|pos| self.move_iterator(pos, &Direction::Left)),
+            Direction::None => Some((i, j)),
+            Direction::Down => {
+                if i >= self.high_y_boundary - 1 {
+                    None
+                } else {
+                    Some((i + 1, j))
+                }
+            }
+            Direction::Right => {
+                if j >= self.high_x_boundary - 1 || i < self.low_y_boundary || i >= self.high_y_boundary {
+                    None
+                } else {
+                    Some((i, j + 1))
+                }
+            }
+            Direction::Left => {
+                if j <= self.low_x_boundary || i < self.low_y_boundary || i >= self.high_y_boundary {
+                    None
+                } else {
+                    Some((i, j - 1))
+                }
+            }
+            Direction::Up => {
+                if i <= self.low_y_boundary {
+                    None
+                } else {
+                    Some((i - 1, j))
+                }
+            }
+        }
+    }
+
+    pub fn step<T: Topology>(
+        &self,
+        pos: (isize, isize),
+        dir: &Direction,
+        topology: &T,
+    ) -> Option<((isize, isize), Direction)> {
+        topology.step(self, pos, dir)
+    }
+
+    pub fn step_array<T: Topology>(
+        &self,
+        (i, j): (usize, usize),
+        dir: &Direction,
+        topology: &T,
+    ) -> Option<((usize, usize), Direction)> {
+        self.step((i as isize, j as isize), dir, topology)
+            .map(|((i, j), dir)| ((i as usize, j as usize), dir))
+    }
+
+    // brings any position back inside, as if the edges were glued to each other
+    pub fn wrap(&self, (i, j): (isize, isize)) -> (isize, isize) {
+        let height = self.high_y_boundary - self.low_y_boundary;
+        let width = self.high_x_boundary - self.low_x_boundary;
+        (
+            self.low_y_boundary + (i - self.low_y_boundary).rem_euclid(height),
+            self.low_x_boundary + (j - self.low_x_boundary).rem_euclid(width),
+        )
+    }
+
+    // a Torus step, a regular move unless it falls off and comes back on the other side
+    pub fn wrap_array_iterator(&self, pos: (usize, usize), dir: &Direction) -> (usize, usize) {
+        self.step_array(pos, dir, &Torus).unwrap().0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn get_direction_strategy() -> impl Strategy<Value = Direction> {
+        prop::sample::select(Direction::get_all_directions().to_vec())
+    }
+
+    // (low_x, low_y, high_x, high_y) together with a position inside of them
+    fn get_bounds_strategy(
+    ) -> impl Strategy<Value = ((isize, isize, isize, isize), (isize, isize))> {
+        let sizes = (-5isize..5, -5isize..5, 1isize..8, 1isize..8);
+        sizes.prop_flat_map(|(low_x, low_y, width, height)| {
+            let bounds = (low_x, low_y, low_x + width, low_y + height);
+            (Just(bounds), (low_y..low_y + height, low_x..low_x + width))
+        })
+    }
+
+    #[test]
+    fn diagonal_rotation_test() {
+        assert_eq!(Direction::Up.rotate_45(&Direction::Right), Direction::UpRight);
+        assert_eq!(Direction::Up.rotate_45(&Direction::Left), Direction::UpLeft);
+        assert_eq!(Direction::UpRight.rotate(&Direction::Right), Direction::DownRight);
+        assert_eq!(Direction::UpLeft.rotate_180(), Direction::DownRight);
+        assert_eq!(Direction::None.rotate_45(&Direction::Right), Direction::None);
+    }
+
+    #[test]
+    fn offset_test() {
+        Direction::get_all_directions().iter().for_each(|dir| {
+            let (dy, dx) = dir.get_offset();
+            assert_eq!(&Direction::from_offset((dy, dx)), dir);
+            assert_eq!(dir.opposite().get_offset(), (-dy, -dx));
+            assert_eq!(dir.is_diagonal(), dy != 0 && dx != 0);
+        });
+    }
+
+    #[test]
+    fn diagonal_move_test() {
+        let boundary = Boundary::new_array_boundary(3, 3);
+
+        assert_eq!(boundary.move_iterator((1, 1), &Direction::UpLeft), Some((0, 0)));
+        assert_eq!(boundary.move_iterator((0, 1), &Direction::UpRight), None);
+        assert_eq!(boundary.wrap_array_iterator((2, 2), &Direction::DownRight), (0, 0));
+        assert_eq!(boundary.wrap((-1, 7)), (2, 1));
+    }
+
+    #[test]
+    fn vertical_entry_test() {
+        // day24 walks into the valley from outside, so only horizontal moves check the rows
+        let boundary = Boundary::new_array_boundary(3, 3);
+
+        assert_eq!(boundary.move_iterator((-1, 0), &Direction::Down), Some((0, 0)));
+        assert_eq!(boundary.move_iterator((3, 2), &Direction::Up), Some((2, 2)));
+        assert_eq!(boundary.move_iterator((-1, 0), &Direction::Right), None);
+        assert_eq!(boundary.move_iterator((3, 2), &Direction::Left), None);
+    }
+
+    proptest! {
+        #[test]
+        fn rotation_is_cyclic(dir in get_direction_strategy()) {
+            type Rotation = fn(&Direction, &Direction) -> Direction;
+            let turn = |dir: Direction, side: &Direction, times: usize, rotation: Rotation| {
+                (0..times).fold(dir, |dir, _| rotation(&dir, side))
+            };
+            prop_assert_eq!(turn(dir, &Direction::Right, 4, Direction::rotate), dir);
+            prop_assert_eq!(turn(dir, &Direction::Left, 4, Direction::rotate), dir);
+            prop_assert_eq!(turn(dir, &Direction::Right, 8, Direction::rotate_45), dir);
+            prop_assert_eq!(turn(dir, &Direction::Right, 2, Direction::rotate), dir.rotate_180());
+            prop_assert_eq!(dir.rotate(&Direction::Right).rotate(&Direction::Left), dir);
+            prop_assert_eq!(dir.rotate(&Direction::Up), dir);
+        }
+
+        #[test]
+        fn move_is_reversible(
+            (bounds, pos) in get_bounds_strategy(),
+            dir in get_direction_strategy(),
+        ) {
+            let boundary = Boundary::new(bounds.0, bounds.1, bounds.2, bounds.3);
+            let (dy, dx) = dir.get_offset();
+            let target = (pos.0 + dy, pos.1 + dx);
+            let is_inside = |(i, j): (isize, isize)| {
+                (bounds.1..bounds.3).contains(&i) && (bounds.0..bounds.2).contains(&j)
+            };
+
+            match boundary.move_iterator(pos, &dir) {
+                Some(new_pos) => {
+                    prop_assert_eq!(new_pos, target);
+                    prop_assert_eq!(boundary.move_iterator(new_pos, &dir.opposite()), Some(pos));
+                }
+                None => prop_assert!(!is_inside(target)),
+            }
+        }
+
+        #[test]
+        fn wrap_stays_in_bounds(
+            width in 1usize..8,
+            height in 1usize..8,
+            seed in any::<(usize, usize)>(),
+            dir in get_direction_strategy(),
+        ) {
+            let boundary = Boundary::new_array_boundary(width, height);
+            let pos = (seed.0 % height, seed.1 % width);
+
+            let (i, j) = boundary.wrap_array_iterator(pos, &dir);
+
+            prop_assert!(i < height && j < width);
+            prop_assert_eq!(boundary.wrap_array_iterator((i, j), &dir.opposite()), pos);
+            match boundary.move_array_iterator(pos, &dir) {
+                Some(new_pos) => prop_assert_eq!((i, j), new_pos),
+                // we land on the opposite edge, so stepping back falls off again
+                None => {
+                    prop_assert_eq!(boundary.move_array_iterator((i, j), &dir.opposite()), None)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::{Boundary, Coordinates, Direction};
+
+#[derive(Debug, Clone)]
+struct Chunk<T> {
+    cells: Vec<Option<T>>,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Storage<T> {
+    Map(HashMap<Coordinates, T>),
+    Chunked(usize, HashMap<Coordinates, Chunk<T>>),
+}
+
+// occupied rows and columns are counted, so the bounding box stays exact when cells get removed
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    storage: Storage<T>,
+    rows: BTreeMap<isize, usize>,
+    columns: BTreeMap<isize, usize>,
+    len: usize,
+}
+
+fn increment(counts: &mut BTreeMap<isize, usize>, key: isize) {
+    *counts.entry(key).or_insert(0) += 1;
+}
+
+fn decrement(counts: &mut BTreeMap<isize, usize>, key: isize) {
+    let count = counts.get_mut(&key).unwrap();
+    *count -= 1;
+    if *count == 0 {
+        counts.remove(&key);
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::with_storage(Storage::Map(HashMap::new()))
+    }
+
+    // keeps cells in square chunks, which is denser for big simulations that fill most of their area
+    pub fn new_chunked(chunk_size: usize) -> SparseGrid<T> {
+        if chunk_size == 0 {
+            panic!("Chunk size must be positive");
+        }
+        SparseGrid::with_storage(Storage::Chunked(chunk_size, HashMap::new()))
+    }
+
+    fn with_storage(storage: Storage<T>) -> SparseGrid<T> {
+        SparseGrid {
+            storage,
+            rows: BTreeMap::new(),
+            columns: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    fn get_chunk_position(chunk_size: usize, coords: &Coordinates) -> (Coordinates, usize) {
+        let size = chunk_size as isize;
+        let chunk = Coordinates::new(
+            coords.get_x().div_euclid(size),
+            coords.get_y().div_euclid(size),
+        );
+        let i = coords.get_y().rem_euclid(size) * size + coords.get_x().rem_euclid(size);
+        (chunk, i as usize)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, coords: &Coordinates) -> Option<&T> {
+        match &self.storage {
+            Storage::Map(cells) => cells.get(coords),
+            Storage::Chunked(chunk_size, chunks) => {
+                let (chunk, i) = SparseGrid::<T>::get_chunk_position(*chunk_size, coords);
+                chunks.get(&chunk).and_then(|chunk| chunk.cells[i].as_ref())
+            }
+        }
+    }
+
+    pub fn get_mut(&mut self, coords: &Coordinates) -> Option<&mut T> {
+        match &mut self.storage {
+            Storage::Map(cells) => cells.get_mut(coords),
+            Storage::Chunked(chunk_size, chunks) => {
+                let (chunk, i) = SparseGrid::<T>::get_chunk_position(*chunk_size, coords);
+                chunks
+                    .get_mut(&chunk)
+                    .and_then(|chunk| chunk.cells[i].as_mut())
+            }
+        }
+    }
+
+    pub fn contains(&self, coords: &Coordinates) -> bool {
+        self.get(coords).is_some()
+    }
+
+    pub fn insert(&mut self, coords: Coordinates, value: T) -> Option<T> {
+        let old_value = match &mut self.storage {
+            Storage::Map(cells) => cells.insert(coords, value),
+            Storage::Chunked(chunk_size, chunks) => {
+                let (chunk, i) = SparseGrid::<T>::get_chunk_position(*chunk_size, &coords);
+                let chunk = chunks.entry(chunk).or_insert_with(|| Chunk {
+                    cells: (0..*chunk_size * *chunk_size).map(|_| None).collect(),
+                    len: 0,
+                });
+                let old_value = chunk.cells[i].replace(value);
+                if old_value.is_none() {
+                    chunk.len += 1;
+                }
+                old_value
+            }
+        };
+        if old_value.is_none() {
+            increment(&mut self.rows, coords.get_y());
+            increment(&mut self.columns, coords.get_x());
+            self.len += 1;
+        }
+        old_value
+    }
+
+    pub fn remove(&mut self, coords: &Coordinates) -> Option<T> {
+        let old_value = match &mut self.storage {
+            Storage::Map(cells) => cells.remove(coords),
+            Storage::Chunked(chunk_size, chunks) => {
+                let (chunk_coords, i) = SparseGrid::<T>::get_chunk_position(*chunk_size, coords);
+                let chunk = chunks.get_mut(&chunk_coords)?;
+                let old_value = chunk.cells[i].take();
+                if old_value.is_some() {
+                    chunk.len -= 1;
+                    if chunk.len == 0 {
+                        chunks.remove(&chunk_coords);
+                    }
+                }
+                old_value
+            }
+        };
+        if old_value.is_some() {
+            decrement(&mut self.rows, coords.get_y());
+            decrement(&mut self.columns, coords.get_x());
+            self.len -= 1;
+        }
+        old_value
+    }
+
+    pub fn iter(&self) -> Box<dyn Iterator<Item = (Coordinates, &T)> + '_> {
+        match &self.storage {
+            Storage::Map(cells) => Box::new(cells.iter().map(|(coords, cell)| (*coords, cell))),
+            Storage::Chunked(chunk_size, chunks) => {
+                let size = *chunk_size as isize;
+                Box::new(chunks.iter().flat_map(move |(chunk, cells)| {
+                    cells.cells.iter().enumerate().filter_map(move |(i, cell)| {
+                        let offset = Coordinates::new(i as isize % size, i as isize / size);
+                        cell.as_ref().map(|cell| (*chunk * size + offset, cell))
+                    })
+                }))
+            }
+        }
+    }
+
+    // inclusive top left and bottom right corners of the occupied area
+    pub fn get_bounds(&self) -> Option<(Coordinates, Coordinates)> {
+        let (min_y, max_y) = (self.rows.keys().next()?, self.rows.keys().next_back()?);
+        let (min_x, max_x) = (
+            self.columns.keys().next()?,
+            self.columns.keys().next_back()?,
+        );
+        Some((
+            Coordinates::new(*min_x, *min_y),
+            Coordinates::new(*max_x, *max_y),
+        ))
+    }
+
+    pub fn get_size(&self) -> (usize, usize) {
+        self.get_bounds().map_or((0, 0), |(min, max)| {
+            (
+                max.get_x().abs_diff(min.get_x()) + 1,
+                max.get_y().abs_diff(min.get_y()) + 1,
+            )
+        })
+    }
+
+    pub fn get_boundary(&self) -> Option<Boundary> {
+        let (min, max) = self.get_bounds()?;
+        Some(Boundary::new(
+            min.get_x(),
+            min.get_y(),
+            max.get_x() + 1,
+            max.get_y() + 1,
+        ))
+    }
+
+    pub fn get_neighbours<'a>(
+        &'a self,
+        coords: &Coordinates,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Direction, Coordinates, &'a T)> + 'a {
+        let coords = *coords;
+        directions.iter().filter_map(move |dir| {
+            let neighbour = coords + *dir;
+            self.get(&neighbour).map(|cell| (*dir, neighbour, cell))
+        })
+    }
+
+    pub fn has_neighbour(&self, coords: &Coordinates, directions: &[Direction]) -> bool {
+        directions
+            .iter()
+            .any(|dir| self.contains(&(*coords + *dir)))
+    }
+
+    pub fn render<F: Fn(Option<&T>) -> char>(&self, render_cell: F) -> String {
+        let (min, max) = match self.get_bounds() {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+        (min.get_y()..=max.get_y())
+            .map(|y| {
+                (min.get_x()..=max.get_x())
+                    .map(|x| render_cell(self.get(&Coordinates::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Coordinates, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coordinates, T)>>(iter: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        iter.into_iter().for_each(|(coords, cell)| {
+            grid.insert(coords, cell);
+        });
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn for_each_storage<F: Fn(SparseGrid<char>)>(check: F) {
+        check(SparseGrid::new());
+        check(SparseGrid::new_chunked(1));
+        check(SparseGrid::new_chunked(4));
+    }
+
+    #[test]
+    fn bounds_test() {
+        for_each_storage(|mut grid| {
+            assert_eq!(grid.get_bounds(), None);
+
+            grid.insert(Coordinates::new(-3, 2), 'a');
+            grid.insert(Coordinates::new(5, -1), 'b');
+            grid.insert(Coordinates::new(5, -1), 'c');
+
+            assert_eq!(grid.len(), 2);
+            assert_eq!(
+                grid.get_bounds(),
+                Some((Coordinates::new(-3, -1), Coordinates::new(5, 2)))
+            );
+            assert_eq!(grid.get_size(), (9, 4));
+
+            assert_eq!(grid.remove(&Coordinates::new(-3, 2)), Some('a'));
+            assert_eq!(grid.remove(&Coordinates::new(-3, 2)), None);
+
+            assert_eq!(grid.get_size(), (1, 1));
+            assert_eq!(grid.get(&Coordinates::new(5, -1)), Some(&'c'));
+        });
+    }
+
+    #[test]
+    fn neighbours_test() {
+        for_each_storage(|mut grid| {
+            grid.insert(Coordinates::new(0, 0), 'a');
+            grid.insert(Coordinates::new(1, 1), 'b');
+            grid.insert(Coordinates::new(-1, 0), 'c');
+
+            let neighbours = grid
+                .get_neighbours(&Coordinates::new(0, 0), Direction::get_all_directions())
+                .map(|(dir, _, cell)| (dir, *cell))
+                .collect::<Vec<(Direction, char)>>();
+
+            assert_eq!(
+                neighbours,
+                vec![(Direction::DownRight, 'b'), (Direction::Left, 'c')]
+            );
+            assert!(!grid.has_neighbour(
+                &Coordinates::new(0, 0),
+                &Direction::get_basic_directions()[..3]
+            ));
+        });
+    }
+
+    #[test]
+    fn iteration_and_render_test() {
+        for_each_storage(|mut grid| {
+            grid.insert(Coordinates::new(-2, -1), '#');
+            grid.insert(Coordinates::new(0, 0), '#');
+            grid.insert(Coordinates::new(3, 0), '#');
+
+            let mut coords = grid
+                .iter()
+                .map(|(coords, _)| coords)
+                .collect::<Vec<Coordinates>>();
+            coords.sort();
+
+            assert_eq!(
+                coords,
+                vec![
+                    Coordinates::new(-2, -1),
+                    Coordinates::new(0, 0),
+                    Coordinates::new(3, 0)
+                ]
+            );
+            assert_eq!(grid.render(|cell| *cell.unwrap_or(&'.')), "#.....\n..#..#");
+        });
+    }
+}
@@ -0,0 +1,196 @@
+use super::{Boundary, Direction};
+
+pub trait Topology {
+    fn step(
+        &self,
+        boundary: &Boundary,
+        pos: (isize, isize),
+        dir: &Direction,
+    ) -> Option<((isize, isize), Direction)>;
+}
+
+pub struct Bounded;
+
+pub struct Clamp;
+
+pub struct Torus;
+
+pub struct RaggedWrap<F: Fn((isize, isize)) -> bool> {
+    is_void: F,
+}
+
+pub struct Glued<F: Fn((isize, isize), &Direction) -> Option<((isize, isize), Direction)>> {
+    glue_edge: F,
+}
+
+impl Topology for Bounded {
+    fn step(
+        &self,
+        boundary: &Boundary,
+        pos: (isize, isize),
+        dir: &Direction,
+    ) -> Option<((isize, isize), Direction)> {
+        boundary.move_iterator(pos, dir).map(|pos| (pos, *dir))
+    }
+}
+
+impl Topology for Clamp {
+    fn step(
+        &self,
+        boundary: &Boundary,
+        pos: (isize, isize),
+        dir: &Direction,
+    ) -> Option<((isize, isize), Direction)> {
+        let (dy, dx) = dir.get_offset();
+        let i = (pos.0 + dy).clamp(boundary.low_y_boundary, boundary.high_y_boundary - 1);
+        let j = (pos.1 + dx).clamp(boundary.low_x_boundary, boundary.high_x_boundary - 1);
+        Some(((i, j), *dir))
+    }
+}
+
+fn wrap_around(boundary: &Boundary, (i, j): (isize, isize), dir: &Direction) -> (isize, isize) {
+    let (dy, dx) = dir.get_offset();
+    boundary.wrap((i + dy, j + dx))
+}
+
+impl Topology for Torus {
+    fn step(
+        &self,
+        boundary: &Boundary,
+        pos: (isize, isize),
+        dir: &Direction,
+    ) -> Option<((isize, isize), Direction)> {
+        Some((wrap_around(boundary, pos, dir), *dir))
+    }
+}
+
+impl<F: Fn((isize, isize)) -> bool> RaggedWrap<F> {
+    pub fn new(is_void: F) -> RaggedWrap<F> {
+        RaggedWrap { is_void }
+    }
+}
+
+impl<F: Fn((isize, isize)) -> bool> Topology for RaggedWrap<F> {
+    fn step(
+        &self,
+        boundary: &Boundary,
+        pos: (isize, isize),
+        dir: &Direction,
+    ) -> Option<((isize, isize), Direction)> {
+        let mut new_pos = wrap_around(boundary, pos, dir);
+        while (self.is_void)(new_pos) {
+            if new_pos == pos {
+                return None;
+            }
+            new_pos = wrap_around(boundary, new_pos, dir);
+        }
+        Some((new_pos, *dir))
+    }
+}
+
+impl<F: Fn((isize, isize), &Direction) -> Option<((isize, isize), Direction)>> Glued<F> {
+    pub fn new(glue_edge: F) -> Glued<F> {
+        Glued { glue_edge }
+    }
+}
+
+impl<F: Fn((isize, isize), &Direction) -> Option<((isize, isize), Direction)>> Topology
+    for Glued<F>
+{
+    fn step(
+        &self,
+        boundary: &Boundary,
+        pos: (isize, isize),
+        dir: &Direction,
+    ) -> Option<((isize, isize), Direction)> {
+        match boundary.move_iterator(pos, dir) {
+            Some(new_pos) => Some((new_pos, *dir)),
+            None => (self.glue_edge)(pos, dir),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_test() {
+        let boundary = Boundary::new_array_boundary(3, 2);
+
+        assert_eq!(boundary.step((0, 2), &Direction::Right, &Bounded), None);
+        assert_eq!(
+            boundary.step((0, 1), &Direction::Right, &Bounded),
+            Some(((0, 2), Direction::Right))
+        );
+    }
+
+    #[test]
+    fn clamp_test() {
+        let boundary = Boundary::new_array_boundary(3, 2);
+
+        assert_eq!(
+            boundary.step((1, 2), &Direction::DownRight, &Clamp),
+            Some(((1, 2), Direction::DownRight))
+        );
+        assert_eq!(
+            boundary.step((1, 2), &Direction::UpRight, &Clamp),
+            Some(((0, 2), Direction::UpRight))
+        );
+    }
+
+    #[test]
+    fn torus_test() {
+        let boundary = Boundary::new(-1, -1, 2, 2);
+
+        assert_eq!(
+            boundary.step((1, 1), &Direction::DownRight, &Torus),
+            Some(((-1, -1), Direction::DownRight))
+        );
+        assert_eq!(
+            boundary.step((-1, 0), &Direction::Up, &Torus),
+            Some(((1, 0), Direction::Up))
+        );
+    }
+
+    #[test]
+    fn ragged_wrap_test() {
+        let map = ["  .#", " ...", "..  "];
+        let boundary = Boundary::new_array_boundary(4, 3);
+        let topology = RaggedWrap::new(|(i, j): (isize, isize)| {
+            map[i as usize].as_bytes()[j as usize] == b' '
+        });
+
+        assert_eq!(
+            boundary.step((0, 3), &Direction::Right, &topology),
+            Some(((0, 2), Direction::Right))
+        );
+        assert_eq!(
+            boundary.step((2, 1), &Direction::Down, &topology),
+            Some(((1, 1), Direction::Down))
+        );
+        assert_eq!(
+            boundary.step((0, 2), &Direction::Up, &topology),
+            Some(((1, 2), Direction::Up))
+        );
+    }
+
+    #[test]
+    fn glued_test() {
+        let boundary = Boundary::new_array_boundary(2, 2);
+        let topology = Glued::new(|(i, _): (isize, isize), dir: &Direction| match dir {
+            Direction::Right => Some(((1 - i, 1), Direction::Left)),
+            _ => None,
+        });
+
+        assert_eq!(
+            boundary.step((0, 1), &Direction::Right, &topology),
+            Some(((1, 1), Direction::Left))
+        );
+        assert_eq!(boundary.step((0, 0), &Direction::Up, &topology), None);
+        assert_eq!(
+            boundary.step((0, 0), &Direction::Down, &topology),
+            Some(((1, 0), Direction::Down))
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+// splitmix64, good enough for generating puzzle inputs and the same on every platform,
+// so a seed always gives back the same input
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // a new generator that doesn't share its sequence with this one
+    pub fn fork(&mut self) -> Rng {
+        Rng::new(self.next_u64())
+    }
+
+    pub fn get_u64(&mut self, range: Range<u64>) -> u64 {
+        if range.is_empty() {
+            panic!("Can't pick a number from an empty range");
+        }
+        let len = range.end - range.start;
+        range.start + ((self.next_u64() as u128 * len as u128) >> 64) as u64
+    }
+
+    pub fn get_usize(&mut self, range: Range<usize>) -> usize {
+        self.get_u64(range.start as u64..range.end as u64) as usize
+    }
+
+    pub fn get_isize(&mut self, range: Range<isize>) -> isize {
+        if range.is_empty() {
+            panic!("Can't pick a number from an empty range");
+        }
+        let len = range.start.abs_diff(range.end) as u64;
+        range.start.wrapping_add(self.get_u64(0..len) as isize)
+    }
+
+    pub fn get_bool(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.get_usize(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.get_usize(0..i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determinism_test() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+
+        assert_eq!(numbers(42), numbers(42));
+        assert_ne!(numbers(42), numbers(43));
+        assert_eq!(Rng::new(1234567).next_u64(), 6457827717110365317);
+    }
+
+    #[test]
+    fn range_test() {
+        let mut rng = Rng::new(7);
+        let mut counts = [0; 6];
+
+        for _ in 0..6000 {
+            let n = rng.get_isize(-3..3);
+            assert!((-3..3).contains(&n));
+            counts[(n + 3) as usize] += 1;
+        }
+        let mut items = (0..10).collect::<Vec<usize>>();
+        rng.shuffle(&mut items);
+        items.sort();
+
+        assert!(counts.iter().all(|count| (800..1200).contains(count)));
+        assert_eq!(items, (0..10).collect::<Vec<usize>>());
+        assert_eq!(rng.get_usize(5..6), 5);
+        assert_eq!(rng.get_isize(isize::MIN..isize::MIN + 1), isize::MIN);
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::plane::{Coordinates, Grid, SparseGrid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+// inclusive top left and bottom right corners of the rendered area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    min: Coordinates,
+    max: Coordinates,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TextOptions {
+    axis_labels: bool,
+    colours: HashMap<char, Colour>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Colour>,
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::new(0, 0, 0);
+    pub const WHITE: Colour = Colour::new(255, 255, 255);
+    pub const GREY: Colour = Colour::new(128, 128, 128);
+    pub const RED: Colour = Colour::new(220, 50, 47);
+    pub const GREEN: Colour = Colour::new(133, 153, 0);
+    pub const BLUE: Colour = Colour::new(38, 139, 210);
+    pub const YELLOW: Colour = Colour::new(181, 137, 0);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Colour {
+        Colour { r, g, b }
+    }
+
+    fn paint(&self, c: char) -> String {
+        format!("\x1b[38;2;{};{};{}m{}\x1b[0m", self.r, self.g, self.b, c)
+    }
+}
+
+impl Viewport {
+    pub fn new(a: Coordinates, b: Coordinates) -> Viewport {
+        Viewport {
+            min: Coordinates::new(a.get_x().min(b.get_x()), a.get_y().min(b.get_y())),
+            max: Coordinates::new(a.get_x().max(b.get_x()), a.get_y().max(b.get_y())),
+        }
+    }
+
+    pub fn from_points<'a, I: IntoIterator<Item = &'a Coordinates>>(points: I) -> Option<Viewport> {
+        points
+            .into_iter()
+            .fold(None, |viewport, point| match viewport {
+                None => Some(Viewport::new(*point, *point)),
+                Some(viewport) => Some(Viewport::new(
+                    Coordinates::new(
+                        viewport.min.get_x().min(point.get_x()),
+                        viewport.min.get_y().min(point.get_y()),
+                    ),
+                    Coordinates::new(
+                        viewport.max.get_x().max(point.get_x()),
+                        viewport.max.get_y().max(point.get_y()),
+                    ),
+                )),
+            })
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>) -> Viewport {
+        Viewport::new(
+            Coordinates::new(0, 0),
+            Coordinates::new(
+                grid.get_width() as isize - 1,
+                grid.get_height() as isize - 1,
+            ),
+        )
+    }
+
+    pub fn from_sparse_grid<T>(grid: &SparseGrid<T>) -> Option<Viewport> {
+        grid.get_bounds().map(|(min, max)| Viewport::new(min, max))
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.max.get_x().abs_diff(self.min.get_x()) + 1
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.max.get_y().abs_diff(self.min.get_y()) + 1
+    }
+
+    pub fn contains(&self, coords: &Coordinates) -> bool {
+        (self.min.get_x()..=self.max.get_x()).contains(&coords.get_x())
+            && (self.min.get_y()..=self.max.get_y()).contains(&coords.get_y())
+    }
+
+    // widens the viewport on every side, handy for showing a bit of empty space around a simulation
+    pub fn expand(&self, margin: isize) -> Viewport {
+        let margin = Coordinates::new(margin, margin);
+        Viewport::new(self.min - margin, self.max + margin)
+    }
+
+    fn rows(&self) -> impl Iterator<Item = isize> {
+        self.min.get_y()..=self.max.get_y()
+    }
+
+    fn columns(&self) -> impl Iterator<Item = isize> {
+        self.min.get_x()..=self.max.get_x()
+    }
+}
+
+impl TextOptions {
+    pub fn new() -> TextOptions {
+        TextOptions::default()
+    }
+
+    pub fn with_axis_labels(mut self) -> TextOptions {
+        self.axis_labels = true;
+        self
+    }
+
+    pub fn with_colour(mut self, c: char, colour: Colour) -> TextOptions {
+        self.colours.insert(c, colour);
+        self
+    }
+}
+
+// columns are labelled vertically like in the puzzle descriptions, the edges and every tenth column get a label
+fn render_column_labels(viewport: &Viewport, gutter: usize) -> Vec<String> {
+    let labels = viewport
+        .columns()
+        .map(|x| {
+            if x == viewport.min.get_x() || x == viewport.max.get_x() || x % 10 == 0 {
+                x.to_string()
+            } else {
+                String::new()
+            }
+        })
+        .collect::<Vec<String>>();
+    let height = labels.iter().map(|label| label.len()).max().unwrap_or(0);
+    (0..height)
+        .map(|i| {
+            let line = labels
+                .iter()
+                .map(|label| {
+                    let padding = height - label.len();
+                    if i < padding {
+                        ' '
+                    } else {
+                        label.as_bytes()[i - padding] as char
+                    }
+                })
+                .collect::<String>();
+            format!("{}{}", " ".repeat(gutter), line)
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+pub fn render_text<F: Fn(&Coordinates) -> char>(
+    viewport: &Viewport,
+    render_cell: F,
+    options: &TextOptions,
+) -> String {
+    let gutter = if options.axis_labels {
+        viewport
+            .rows()
+            .map(|y| y.to_string().len())
+            .max()
+            .unwrap_or(0)
+            + 1
+    } else {
+        0
+    };
+    let mut lines = if options.axis_labels {
+        render_column_labels(viewport, gutter)
+    } else {
+        vec![]
+    };
+    viewport.rows().for_each(|y| {
+        let mut line = if options.axis_labels {
+            format!("{:>width$} ", y, width = gutter - 1)
+        } else {
+            String::new()
+        };
+        viewport.columns().for_each(|x| {
+            let c = render_cell(&Coordinates::new(x, y));
+            match options.colours.get(&c) {
+                Some(colour) => line.push_str(&colour.paint(c)),
+                None => line.push(c),
+            }
+        });
+        lines.push(line);
+    });
+    lines.join("\n")
+}
+
+impl Image {
+    pub fn render<F: Fn(&Coordinates) -> Colour>(
+        viewport: &Viewport,
+        render_cell: F,
+        scale: usize,
+    ) -> Image {
+        if scale == 0 {
+            panic!("Image scale must be positive");
+        }
+        let (width, height) = (viewport.get_width() * scale, viewport.get_height() * scale);
+        let cells = viewport
+            .rows()
+            .flat_map(|y| viewport.columns().map(move |x| Coordinates::new(x, y)))
+            .map(|coords| render_cell(&coords))
+            .collect::<Vec<Colour>>();
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cells[(y / scale) * viewport.get_width() + x / scale])
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<Colour> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    fn get_rgb_bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|pixel| [pixel.r, pixel.g, pixel.b])
+            .collect()
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.get_rgb_bytes());
+        ppm
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.get_rgb_bytes()))
+            .expect("Can't encode PNG image");
+        png
+    }
+
+    // the format is picked based on the file extension
+    pub fn save(&self, path: &Path) {
+        let data = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => panic!("Unsupported image format for {}", path.display()),
+        };
+        fs::write(path, data).expect("Can't save the image");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_grid() -> Grid<char> {
+        Grid::parse("#..\n.#.", |c| c)
+    }
+
+    #[test]
+    fn text_test() {
+        let grid = get_grid();
+        let viewport = Viewport::from_grid(&grid);
+
+        let text = render_text(
+            &viewport,
+            |coords| *grid.get(coords).unwrap(),
+            &TextOptions::new(),
+        );
+
+        assert_eq!(text, grid.to_string());
+    }
+
+    #[test]
+    fn axis_labels_test() {
+        let viewport = Viewport::new(Coordinates::new(8, -1), Coordinates::new(12, 1));
+
+        let text = render_text(&viewport, |_| '.', &TextOptions::new().with_axis_labels());
+
+        assert_eq!(text, "     1 1\n   8 0 2\n-1 .....\n 0 .....\n 1 .....");
+    }
+
+    #[test]
+    fn colour_test() {
+        let viewport = Viewport::new(Coordinates::new(0, 0), Coordinates::new(1, 0));
+        let options = TextOptions::new().with_colour('#', Colour::RED);
+
+        let text = render_text(
+            &viewport,
+            |coords| ['#', '.'][coords.get_x() as usize],
+            &options,
+        );
+
+        assert_eq!(text, "\x1b[38;2;220;50;47m#\x1b[0m.");
+    }
+
+    #[test]
+    fn image_test() {
+        let grid = get_grid();
+        let viewport = Viewport::from_grid(&grid);
+
+        let image = Image::render(
+            &viewport,
+            |coords| match grid.get(coords) {
+                Some('#') => Colour::WHITE,
+                _ => Colour::BLACK,
+            },
+            2,
+        );
+
+        assert_eq!((image.get_width(), image.get_height()), (6, 4));
+        assert_eq!(image.get_pixel(1, 1), Some(Colour::WHITE));
+        assert_eq!(image.get_pixel(2, 1), Some(Colour::BLACK));
+        assert_eq!(image.get_pixel(3, 3), Some(Colour::WHITE));
+        assert!(image.to_ppm().starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(image.to_ppm().len(), 11 + 6 * 4 * 3);
+        assert!(image.to_png().starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+}
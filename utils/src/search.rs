@@ -0,0 +1,328 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    cost: usize,
+    states: Vec<S>,
+}
+
+impl<S> Path<S> {
+    pub fn get_cost(&self) -> usize {
+        self.cost
+    }
+
+    pub fn get_states(&self) -> &[S] {
+        &self.states
+    }
+
+    pub fn get_start(&self) -> &S {
+        self.states.first().unwrap()
+    }
+
+    pub fn get_goal(&self) -> &S {
+        self.states.last().unwrap()
+    }
+
+    pub fn into_states(self) -> Vec<S> {
+        self.states
+    }
+}
+
+// every visited state is kept once together with the index of its parent
+struct Arena<S> {
+    nodes: Vec<(Option<S>, Option<usize>)>,
+}
+
+impl<S> Arena<S> {
+    fn new() -> Arena<S> {
+        Arena { nodes: vec![] }
+    }
+
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((Some(state), parent));
+        self.nodes.len() - 1
+    }
+
+    fn get(&self, i: usize) -> &S {
+        self.nodes[i].0.as_ref().unwrap()
+    }
+
+    fn into_path(mut self, mut i: usize, cost: usize) -> Path<S> {
+        let mut states = vec![];
+        loop {
+            let (state, parent) = &mut self.nodes[i];
+            states.push(state.take().unwrap());
+            match parent {
+                Some(parent) => i = *parent,
+                None => break,
+            }
+        }
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+pub fn bfs<S, I, N, FS, FG>(starts: I, successors: FS, is_goal: FG) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> N,
+    FG: FnMut(&S) -> bool,
+{
+    bfs_by_key(starts, successors, is_goal, |state| state.clone())
+}
+
+// states sharing a key are treated as the same state, e.g. positions at times equal modulo some period
+pub fn bfs_by_key<S, K, I, N, FS, FG, FK>(
+    starts: I,
+    mut successors: FS,
+    mut is_goal: FG,
+    mut get_key: FK,
+) -> Option<Path<S>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> N,
+    FG: FnMut(&S) -> bool,
+    FK: FnMut(&S) -> K,
+{
+    let mut arena = Arena::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if seen.insert(get_key(&start)) {
+            queue.push_back((arena.push(start, None), 0));
+        }
+    }
+    while let Some((i, depth)) = queue.pop_front() {
+        if is_goal(arena.get(i)) {
+            return Some(arena.into_path(i, depth));
+        }
+        for next in successors(arena.get(i)) {
+            if seen.insert(get_key(&next)) {
+                queue.push_back((arena.push(next, Some(i)), depth + 1));
+            }
+        }
+    }
+    None
+}
+
+pub fn dijkstra<S, I, N, FS, FG>(starts: I, successors: FS, is_goal: FG) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = (S, usize)>,
+    FS: FnMut(&S) -> N,
+    FG: FnMut(&S) -> bool,
+{
+    astar_by_key(starts, successors, |_| 0, is_goal, |state| state.clone())
+}
+
+pub fn dijkstra_by_key<S, K, I, N, FS, FG, FK>(
+    starts: I,
+    successors: FS,
+    is_goal: FG,
+    get_key: FK,
+) -> Option<Path<S>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = (S, usize)>,
+    FS: FnMut(&S) -> N,
+    FG: FnMut(&S) -> bool,
+    FK: FnMut(&S) -> K,
+{
+    astar_by_key(starts, successors, |_| 0, is_goal, get_key)
+}
+
+pub fn astar<S, I, N, FS, FH, FG>(
+    starts: I,
+    successors: FS,
+    heuristic: FH,
+    is_goal: FG,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = (S, usize)>,
+    FS: FnMut(&S) -> N,
+    FH: FnMut(&S) -> usize,
+    FG: FnMut(&S) -> bool,
+{
+    astar_by_key(starts, successors, heuristic, is_goal, |state| {
+        state.clone()
+    })
+}
+
+// the heuristic must never overestimate the remaining cost, otherwise the returned path might not be the cheapest
+pub fn astar_by_key<S, K, I, N, FS, FH, FG, FK>(
+    starts: I,
+    mut successors: FS,
+    mut heuristic: FH,
+    mut is_goal: FG,
+    mut get_key: FK,
+) -> Option<Path<S>>
+where
+    K: Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: IntoIterator<Item = (S, usize)>,
+    FS: FnMut(&S) -> N,
+    FH: FnMut(&S) -> usize,
+    FG: FnMut(&S) -> bool,
+    FK: FnMut(&S) -> K,
+{
+    let mut arena = Arena::new();
+    let mut best_costs = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let key = get_key(&start);
+        if let Entry::Vacant(entry) = best_costs.entry(key) {
+            entry.insert(0);
+            let estimate = heuristic(&start);
+            heap.push(Reverse((estimate, 0, arena.push(start, None))));
+        }
+    }
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // a cheaper way to this state was found after this entry got queued
+        if best_costs[&get_key(arena.get(i))] < cost {
+            continue;
+        }
+        if is_goal(arena.get(i)) {
+            return Some(arena.into_path(i, cost));
+        }
+        for (next, step_cost) in successors(arena.get(i)) {
+            let next_cost = cost + step_cost;
+            let key = get_key(&next);
+            if best_costs.get(&key).is_none_or(|best| next_cost < *best) {
+                best_costs.insert(key, next_cost);
+                let estimate = next_cost + heuristic(&next);
+                heap.push(Reverse((estimate, next_cost, arena.push(next, Some(i)))));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_maze() -> Vec<&'static [u8]> {
+        vec![
+            b"S..#....",
+            b".#.#.##.",
+            b".#...#..",
+            b".####.#.",
+            b"......#E",
+        ]
+    }
+
+    fn get_maze_successors(maze: &[&[u8]], (i, j): &(usize, usize)) -> Vec<(usize, usize)> {
+        [(0, 1), (1, 0), (0, -1), (-1, 0)]
+            .iter()
+            .map(|(di, dj)| (*i as isize + di, *j as isize + dj))
+            .filter(|(i, j)| {
+                *i >= 0 && *j >= 0 && (*i as usize) < maze.len() && (*j as usize) < maze[0].len()
+            })
+            .map(|(i, j)| (i as usize, j as usize))
+            .filter(|(i, j)| maze[*i][*j] != b'#')
+            .collect()
+    }
+
+    #[test]
+    fn bfs_test() {
+        let maze = get_maze();
+
+        let path = bfs(
+            [(0, 0)],
+            |pos| get_maze_successors(&maze, pos),
+            |pos| maze[pos.0][pos.1] == b'E',
+        )
+        .unwrap();
+
+        assert_eq!(path.get_cost(), 15);
+        assert_eq!(path.get_states().len(), 16);
+        assert_eq!(path.get_start(), &(0, 0));
+        assert_eq!(path.get_goal(), &(4, 7));
+        assert!(path
+            .get_states()
+            .windows(2)
+            .all(|pair| get_maze_successors(&maze, &pair[0]).contains(&pair[1])));
+        assert_eq!(
+            bfs([(0, 0)], |pos| get_maze_successors(&maze, pos), |_| false),
+            None
+        );
+    }
+
+    #[test]
+    fn multi_source_test() {
+        let maze = get_maze();
+
+        let path = bfs(
+            [(0, 0), (2, 4)],
+            |pos| get_maze_successors(&maze, pos),
+            |pos| maze[pos.0][pos.1] == b'E',
+        )
+        .unwrap();
+
+        assert_eq!(path.get_start(), &(2, 4));
+        assert_eq!(path.get_cost(), 9);
+    }
+
+    #[test]
+    fn key_test() {
+        // walking around a cycle of length 5, states with the same position modulo 5 are equal
+        let path = bfs_by_key([0usize], |n| [n + 1, n + 2], |n| *n == 13, |n| *n % 5);
+
+        assert_eq!(path, None);
+
+        let path = bfs_by_key([0usize], |n| [n + 1, n + 2], |n| *n == 4, |n| *n % 5).unwrap();
+
+        assert_eq!(path.into_states(), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn weighted_test() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('a', 7), ('c', 10), ('d', 15)]),
+            ('c', vec![('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+            ('d', vec![('b', 15), ('c', 11), ('e', 6)]),
+            ('e', vec![('d', 6), ('f', 9)]),
+            ('f', vec![('a', 14), ('c', 2), ('e', 9)]),
+        ]);
+
+        let path = dijkstra(['a'], |node| edges[node].clone(), |node| *node == 'e').unwrap();
+
+        assert_eq!(path.get_cost(), 20);
+        assert_eq!(path.into_states(), vec!['a', 'c', 'f', 'e']);
+    }
+
+    #[test]
+    fn astar_test() {
+        let maze = get_maze();
+        let goal = (4, 7);
+        let successors = |pos: &(usize, usize)| {
+            get_maze_successors(&maze, pos)
+                .into_iter()
+                .map(|next| (next, 1))
+                .collect::<Vec<((usize, usize), usize)>>()
+        };
+
+        let path = astar(
+            [(0, 0)],
+            successors,
+            |pos: &(usize, usize)| pos.0.abs_diff(goal.0) + pos.1.abs_diff(goal.1),
+            |pos| *pos == goal,
+        )
+        .unwrap();
+        let reference = dijkstra([(0, 0)], successors, |pos| *pos == goal).unwrap();
+
+        assert_eq!(path.get_cost(), reference.get_cost());
+    }
+}
@@ -0,0 +1,437 @@
+use std::collections::{HashSet, VecDeque};
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    x: isize,
+    y: isize,
+    z: isize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction3 {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    min: Point3,
+    max: Point3,
+}
+
+pub trait VoxelSet {
+    fn contains(&self, point: &Point3) -> bool;
+    fn points(&self) -> Vec<Point3>;
+
+    fn get_bounding_box(&self) -> Option<BoundingBox> {
+        BoundingBox::from_points(self.points().iter())
+    }
+
+    fn surface_area(&self) -> usize {
+        self.points()
+            .iter()
+            .map(|point| {
+                point
+                    .neighbors6()
+                    .iter()
+                    .filter(|neighbour| !self.contains(neighbour))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn exterior(&self) -> SparseVoxels {
+        let mut exterior = SparseVoxels::new();
+        let bounding_box = match self.get_bounding_box() {
+            Some(bounding_box) => bounding_box.expand(1),
+            None => return exterior,
+        };
+        let mut queue = VecDeque::from([bounding_box.min]);
+        exterior.insert(bounding_box.min);
+        while let Some(point) = queue.pop_front() {
+            point.neighbors6().iter().for_each(|neighbour| {
+                if bounding_box.contains(neighbour)
+                    && !self.contains(neighbour)
+                    && exterior.insert(*neighbour)
+                {
+                    queue.push_back(*neighbour);
+                }
+            });
+        }
+        exterior
+    }
+
+    fn exterior_surface_area(&self) -> usize {
+        let exterior = self.exterior();
+        self.points()
+            .iter()
+            .map(|point| {
+                point
+                    .neighbors6()
+                    .iter()
+                    .filter(|neighbour| exterior.contains(neighbour))
+                    .count()
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseVoxels {
+    points: HashSet<Point3>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseVoxels {
+    bounding_box: BoundingBox,
+    cells: Vec<bool>,
+}
+
+impl Point3 {
+    pub fn new(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn get_x(&self) -> isize {
+        self.x
+    }
+
+    pub fn get_y(&self) -> isize {
+        self.y
+    }
+
+    pub fn get_z(&self) -> isize {
+        self.z
+    }
+
+    pub fn manhattan_distance(&self, other: &Point3) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Point3) -> usize {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    pub fn neighbors6(&self) -> [Point3; 6] {
+        Direction3::get_face_directions().map(|dir| *self + dir)
+    }
+
+    pub fn neighbors26(&self) -> Vec<Point3> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Point3::new(x, y, z))))
+            .filter(|offset| offset != &Point3::default())
+            .map(|offset| *self + offset)
+            .collect()
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Point3 {
+        Point3 { x, y, z }
+    }
+}
+
+impl From<Direction3> for Point3 {
+    fn from(dir: Direction3) -> Point3 {
+        Point3::from(dir.get_offset())
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Add<Direction3> for Point3 {
+    type Output = Point3;
+
+    fn add(self, dir: Direction3) -> Point3 {
+        self + Point3::from(dir)
+    }
+}
+
+impl Mul<isize> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, factor: isize) -> Point3 {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Direction3 {
+    pub fn get_face_directions() -> &'static [Direction3; 6] {
+        static DIRECTIONS: [Direction3; 6] = [
+            Direction3::PosX,
+            Direction3::NegX,
+            Direction3::PosY,
+            Direction3::NegY,
+            Direction3::PosZ,
+            Direction3::NegZ,
+        ];
+        &DIRECTIONS
+    }
+
+    pub fn get_offset(&self) -> (isize, isize, isize) {
+        match self {
+            Direction3::PosX => (1, 0, 0),
+            Direction3::NegX => (-1, 0, 0),
+            Direction3::PosY => (0, 1, 0),
+            Direction3::NegY => (0, -1, 0),
+            Direction3::PosZ => (0, 0, 1),
+            Direction3::NegZ => (0, 0, -1),
+        }
+    }
+
+    pub fn opposite(&self) -> Direction3 {
+        match self {
+            Direction3::PosX => Direction3::NegX,
+            Direction3::NegX => Direction3::PosX,
+            Direction3::PosY => Direction3::NegY,
+            Direction3::NegY => Direction3::PosY,
+            Direction3::PosZ => Direction3::NegZ,
+            Direction3::NegZ => Direction3::PosZ,
+        }
+    }
+}
+
+impl BoundingBox {
+    pub fn new(min: Point3, max: Point3) -> BoundingBox {
+        BoundingBox {
+            min: Point3::new(min.x.min(max.x), min.y.min(max.y), min.z.min(max.z)),
+            max: Point3::new(min.x.max(max.x), min.y.max(max.y), min.z.max(max.z)),
+        }
+    }
+
+    pub fn from_points<'a, I: Iterator<Item = &'a Point3>>(mut points: I) -> Option<BoundingBox> {
+        let first = points.next()?;
+        Some(points.fold(BoundingBox::new(*first, *first), |bounding_box, point| {
+            bounding_box.extend(point)
+        }))
+    }
+
+    pub fn get_min(&self) -> Point3 {
+        self.min
+    }
+
+    pub fn get_max(&self) -> Point3 {
+        self.max
+    }
+
+    pub fn extend(&self, point: &Point3) -> BoundingBox {
+        BoundingBox {
+            min: Point3::new(
+                self.min.x.min(point.x),
+                self.min.y.min(point.y),
+                self.min.z.min(point.z),
+            ),
+            max: Point3::new(
+                self.max.x.max(point.x),
+                self.max.y.max(point.y),
+                self.max.z.max(point.z),
+            ),
+        }
+    }
+
+    pub fn expand(&self, margin: isize) -> BoundingBox {
+        let margin = Point3::new(margin, margin, margin);
+        BoundingBox::new(self.min - margin, self.max + margin)
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    pub fn get_size(&self) -> (usize, usize, usize) {
+        (
+            self.max.x.abs_diff(self.min.x) + 1,
+            self.max.y.abs_diff(self.min.y) + 1,
+            self.max.z.abs_diff(self.min.z) + 1,
+        )
+    }
+
+    pub fn get_volume(&self) -> usize {
+        let (x, y, z) = self.get_size();
+        x * y * z
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Point3> {
+        let (min, max) = (self.min, self.max);
+        (min.x..=max.x).flat_map(move |x| {
+            (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| Point3::new(x, y, z)))
+        })
+    }
+}
+
+impl SparseVoxels {
+    pub fn new() -> SparseVoxels {
+        SparseVoxels::default()
+    }
+
+    pub fn insert(&mut self, point: Point3) -> bool {
+        self.points.insert(point)
+    }
+
+    pub fn remove(&mut self, point: &Point3) -> bool {
+        self.points.remove(point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+impl FromIterator<Point3> for SparseVoxels {
+    fn from_iter<I: IntoIterator<Item = Point3>>(iter: I) -> SparseVoxels {
+        SparseVoxels {
+            points: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl VoxelSet for SparseVoxels {
+    fn contains(&self, point: &Point3) -> bool {
+        self.points.contains(point)
+    }
+
+    fn points(&self) -> Vec<Point3> {
+        self.points.iter().copied().collect()
+    }
+}
+
+impl DenseVoxels {
+    pub fn new(bounding_box: BoundingBox) -> DenseVoxels {
+        DenseVoxels {
+            bounding_box,
+            cells: vec![false; bounding_box.get_volume()],
+        }
+    }
+
+    fn get_index(&self, point: &Point3) -> Option<usize> {
+        if !self.bounding_box.contains(point) {
+            return None;
+        }
+        let (_, size_y, size_z) = self.bounding_box.get_size();
+        let offset = *point - self.bounding_box.min;
+        Some((offset.x as usize * size_y + offset.y as usize) * size_z + offset.z as usize)
+    }
+
+    pub fn insert(&mut self, point: Point3) -> bool {
+        let i = self
+            .get_index(&point)
+            .expect("Point lies outside of dense voxel set bounds");
+        !std::mem::replace(&mut self.cells[i], true)
+    }
+
+    pub fn remove(&mut self, point: &Point3) -> bool {
+        match self.get_index(point) {
+            Some(i) => std::mem::replace(&mut self.cells[i], false),
+            None => false,
+        }
+    }
+}
+
+impl VoxelSet for DenseVoxels {
+    fn contains(&self, point: &Point3) -> bool {
+        self.get_index(point).is_some_and(|i| self.cells[i])
+    }
+
+    fn points(&self) -> Vec<Point3> {
+        self.bounding_box
+            .iter()
+            .zip(self.cells.iter())
+            .filter(|(_, cell)| **cell)
+            .map(|(point, _)| point)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_hollow_cube() -> SparseVoxels {
+        BoundingBox::new(Point3::new(0, 0, 0), Point3::new(2, 2, 2))
+            .iter()
+            .filter(|point| point != &Point3::new(1, 1, 1))
+            .collect()
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let point = Point3::new(1, -1, 0);
+
+        assert!(point.neighbors6().iter().all(|n| point.manhattan_distance(n) == 1));
+        assert_eq!(point.neighbors26().len(), 26);
+        assert!(point.neighbors26().iter().all(|n| point.chebyshev_distance(n) == 1));
+        assert_eq!(point + Direction3::PosZ.opposite(), Point3::new(1, -1, -1));
+    }
+
+    #[test]
+    fn bounding_box_test() {
+        let points = [Point3::new(1, 5, -2), Point3::new(-3, 2, 4)];
+
+        let bounding_box = BoundingBox::from_points(points.iter()).unwrap();
+
+        assert_eq!(bounding_box.get_min(), Point3::new(-3, 2, -2));
+        assert_eq!(bounding_box.get_max(), Point3::new(1, 5, 4));
+        assert_eq!(bounding_box.get_volume(), 5 * 4 * 7);
+        assert_eq!(bounding_box.iter().count(), bounding_box.get_volume());
+        assert!(BoundingBox::from_points([].iter()).is_none());
+    }
+
+    #[test]
+    fn surface_area_test() {
+        let voxels = get_hollow_cube();
+
+        assert_eq!(voxels.surface_area(), 54 + 6);
+        assert_eq!(voxels.exterior_surface_area(), 54);
+    }
+
+    #[test]
+    fn dense_voxels_test() {
+        let sparse = get_hollow_cube();
+        let mut dense = DenseVoxels::new(sparse.get_bounding_box().unwrap());
+        sparse.points().iter().for_each(|point| {
+            dense.insert(*point);
+        });
+
+        assert!(!dense.contains(&Point3::new(1, 1, 1)));
+        assert!(!dense.contains(&Point3::new(5, 1, 1)));
+        assert_eq!(dense.points().len(), 26);
+        assert_eq!(dense.surface_area(), sparse.surface_area());
+        assert_eq!(dense.exterior_surface_area(), sparse.exterior_surface_area());
+    }
+}